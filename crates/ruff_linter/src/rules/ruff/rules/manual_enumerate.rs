@@ -0,0 +1,269 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::visitor::{walk_expr, walk_stmt, Visitor};
+use ruff_python_ast::{Expr, ExprContext, Operator, Stmt, StmtFor};
+use ruff_text_size::{Ranged, TextRange};
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for a manually maintained counter variable that's incremented
+/// once per iteration of a `for` loop, where `enumerate()` would do the
+/// same job.
+///
+/// ## Why is this bad?
+/// A hand-rolled counter is one more thing that can drift out of sync with
+/// the loop (e.g. if the increment is accidentally duplicated or dropped
+/// in a later edit); `enumerate()` ties the index to the iteration itself.
+///
+/// ## Example
+/// ```python
+/// idx = 0
+/// for item in items:
+///     print(idx, item)
+///     idx += 1
+/// ```
+///
+/// Use instead:
+/// ```python
+/// for idx, item in enumerate(items):
+///     print(idx, item)
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct ManualEnumerate {
+    counter: String,
+}
+
+impl Violation for ManualEnumerate {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let ManualEnumerate { counter } = self;
+        format!("Use `enumerate()` instead of manually incrementing `{counter}`")
+    }
+}
+
+/// RUF304
+pub(crate) fn manual_enumerate(checker: &mut Checker, body: &[Stmt]) {
+    for window in body.windows(2) {
+        let [init_stmt, Stmt::For(for_stmt)] = window else {
+            continue;
+        };
+        let Some((counter, start_range, increment_stmt)) = match_manual_counter(init_stmt, for_stmt)
+        else {
+            continue;
+        };
+
+        let mut diagnostic = Diagnostic::new(
+            ManualEnumerate {
+                counter: counter.to_string(),
+            },
+            increment_stmt.range(),
+        );
+
+        let start_text = checker.locator().slice(start_range);
+        let start_suffix = if start_text == "0" {
+            String::new()
+        } else {
+            format!(", start={start_text}")
+        };
+        let iter_text = checker.locator().slice(for_stmt.iter.range());
+        let target_text = checker.locator().slice(for_stmt.target.range());
+        let header = format!("{counter}, {target_text} in enumerate({iter_text}{start_suffix})");
+
+        let header_edit = Edit::range_replacement(
+            header,
+            TextRange::new(for_stmt.target.range().start(), for_stmt.iter.range().end()),
+        );
+        let fix = Fix::unsafe_edits(
+            header_edit,
+            [
+                Edit::range_deletion(init_stmt.range()),
+                Edit::range_deletion(increment_stmt.range()),
+            ],
+        );
+        diagnostic.set_fix(fix);
+
+        checker.diagnostics.push(diagnostic);
+    }
+}
+
+/// Whether `init_stmt` followed by `for_stmt` is a hand-rolled counter that
+/// `enumerate()` could replace: `init_stmt` sets an integer counter that
+/// isn't the loop's own target, and the loop body increments it by exactly
+/// one, unconditionally, with no other write to it anywhere else in the
+/// body. Returns the counter's name, the range of its initial value, and
+/// the increment statement. Factored out of [`manual_enumerate`] so it can
+/// be exercised directly in tests without the `Checker` plumbing that
+/// function needs.
+fn match_manual_counter<'a>(
+    init_stmt: &'a Stmt,
+    for_stmt: &'a StmtFor,
+) -> Option<(&'a str, TextRange, &'a Stmt)> {
+    let (counter, start_range) = match_integer_initializer(init_stmt)?;
+    if is_name(for_stmt.target.as_ref(), counter) {
+        return None;
+    }
+
+    // The increment must appear directly in the loop body (not nested
+    // inside an `if`/`try`/etc., i.e. it must run unconditionally on every
+    // iteration), and there must be exactly one of them.
+    let mut increments = for_stmt
+        .body
+        .iter()
+        .filter(|stmt| is_unit_increment(stmt, counter));
+    let increment_stmt = increments.next()?;
+    if increments.next().is_some() {
+        return None;
+    }
+
+    // Reading the counter elsewhere is fine -- `enumerate()` keeps it
+    // validly bound for the rest of the body, which is exactly why the
+    // canonical `idx = 0; for item in items: print(idx, item); idx +=
+    // 1` pattern should be flagged. Another *write* to it, though, we
+    // don't know how to carry over to the enumerate index, so that
+    // disqualifies the fix.
+    let other_write = for_stmt.body.iter().any(|stmt| {
+        !std::ptr::eq(stmt, increment_stmt) && {
+            let mut usage = NameUsage {
+                name: counter,
+                found: false,
+            };
+            usage.visit_stmt(stmt);
+            usage.found
+        }
+    });
+    if other_write {
+        return None;
+    }
+
+    Some((counter, start_range, increment_stmt))
+}
+
+/// Matches `name = <int literal>`, returning the counter's name and the
+/// range of the literal (so the fix can reuse its exact source text as
+/// `enumerate(..., start=...)`).
+fn match_integer_initializer(stmt: &Stmt) -> Option<(&str, TextRange)> {
+    let Stmt::Assign(assign) = stmt else {
+        return None;
+    };
+    let [Expr::Name(target)] = assign.targets.as_slice() else {
+        return None;
+    };
+    let Expr::NumberLiteral(literal) = assign.value.as_ref() else {
+        return None;
+    };
+    if literal.value.as_int().is_none() {
+        return None;
+    }
+    Some((target.id.as_str(), literal.range()))
+}
+
+/// Whether `stmt` is exactly `{name} += 1`.
+fn is_unit_increment(stmt: &Stmt, name: &str) -> bool {
+    let Stmt::AugAssign(aug_assign) = stmt else {
+        return false;
+    };
+    if aug_assign.op != Operator::Add || !is_name(aug_assign.target.as_ref(), name) {
+        return false;
+    }
+    matches!(
+        aug_assign.value.as_ref(),
+        Expr::NumberLiteral(literal) if literal.value.as_int().is_some_and(|n| n == 1)
+    )
+}
+
+fn is_name(expr: &Expr, name: &str) -> bool {
+    matches!(expr, Expr::Name(n) if n.id.as_str() == name)
+}
+
+/// Looks for another *write* to `name` -- an assignment target, an
+/// augmented assignment, a `for` target, etc. (anything whose `Expr::Name`
+/// has `ExprContext::Store` or `ExprContext::Del`). A plain read doesn't
+/// set `found`, since `enumerate()` keeps the counter validly bound for
+/// reads elsewhere in the body.
+struct NameUsage<'a> {
+    name: &'a str,
+    found: bool,
+}
+
+impl<'a> Visitor<'a> for NameUsage<'a> {
+    fn visit_stmt(&mut self, stmt: &'a Stmt) {
+        if self.found {
+            return;
+        }
+        walk_stmt(self, stmt);
+    }
+
+    fn visit_expr(&mut self, expr: &'a Expr) {
+        if self.found {
+            return;
+        }
+        if let Expr::Name(name) = expr {
+            if name.id.as_str() == self.name
+                && matches!(name.ctx, ExprContext::Store | ExprContext::Del)
+            {
+                self.found = true;
+            }
+            return;
+        }
+        walk_expr(self, expr);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ruff_python_parser::parse_module;
+
+    /// Parses `source` (a single function definition) and runs
+    /// [`match_manual_counter`] on every `init_stmt, for_stmt` pair,
+    /// returning whether any of them matches.
+    fn check(source: &str) -> bool {
+        let module = parse_module(source).unwrap();
+        let Stmt::FunctionDef(func) = &module.into_syntax().body[0] else {
+            panic!("expected a function definition");
+        };
+        func.body.windows(2).any(|window| {
+            let [init_stmt, Stmt::For(for_stmt)] = window else {
+                return false;
+            };
+            match_manual_counter(init_stmt, for_stmt).is_some()
+        })
+    }
+
+    #[test]
+    fn counter_read_elsewhere_in_the_body_is_flagged() {
+        let source = r#"
+def f(items):
+    idx = 0
+    for item in items:
+        print(idx, item)
+        idx += 1
+"#;
+        assert!(check(source));
+    }
+
+    #[test]
+    fn another_write_to_the_counter_disqualifies_the_fix() {
+        let source = r#"
+def f(items):
+    idx = 0
+    for item in items:
+        print(idx, item)
+        idx += 1
+        idx = fixup(idx)
+"#;
+        assert!(!check(source));
+    }
+
+    #[test]
+    fn missing_increment_is_not_flagged() {
+        let source = r#"
+def f(items):
+    idx = 0
+    for item in items:
+        print(idx, item)
+"#;
+        assert!(!check(source));
+    }
+}
@@ -0,0 +1,245 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{ExceptHandler, Expr, StmtFor};
+use ruff_text_size::{Ranged, TextRange};
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for a `for i in range(...)` loop that reassigns, in its own
+/// body, a name used as one of `range`'s `start`/`stop`/`step` arguments.
+///
+/// ## Why is this bad?
+/// Python evaluates `range`'s arguments exactly once, before the loop
+/// starts iterating -- the resulting sequence of indices is fixed from
+/// that point on. Reassigning `stop` (or `start`/`step`) inside the loop
+/// doesn't extend or shrink how many times it runs; it's a common source
+/// of bugs where the author expected the loop to re-read the bound.
+///
+/// ## Example
+/// ```python
+/// stop = 10
+/// for i in range(stop):
+///     if found(i):
+///         stop = i  # has no effect on the remaining iterations
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct MutRangeBound {
+    name: String,
+}
+
+impl Violation for MutRangeBound {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let MutRangeBound { name } = self;
+        format!(
+            "Mutating `{name}` in the loop body has no effect -- `range()`'s arguments are evaluated once, before the loop starts"
+        )
+    }
+}
+
+/// RUF306
+pub(crate) fn mut_range_bound(checker: &mut Checker, for_stmt: &StmtFor) {
+    let Some(bound_names) = range_bound_names(&for_stmt.iter) else {
+        return;
+    };
+    if bound_names.is_empty() {
+        return;
+    }
+
+    let mut offenses = Vec::new();
+    collect_bound_mutations(&for_stmt.body, &bound_names, &mut offenses);
+
+    for (range, name) in offenses {
+        checker.diagnostics.push(Diagnostic::new(
+            MutRangeBound {
+                name: name.to_string(),
+            },
+            range,
+        ));
+    }
+}
+
+/// Returns the bare names passed as `range(...)`'s arguments (literal
+/// bounds like `range(10)` contribute nothing and are silently skipped).
+fn range_bound_names(iter: &Expr) -> Option<Vec<&str>> {
+    let Expr::Call(call) = iter else { return None };
+    let Expr::Name(func) = call.func.as_ref() else {
+        return None;
+    };
+    if func.id.as_str() != "range" || !call.arguments.keywords.is_empty() {
+        return None;
+    }
+    Some(
+        call.arguments
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                Expr::Name(name) => Some(name.id.as_str()),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+fn collect_bound_mutations<'a>(
+    body: &[ruff_python_ast::Stmt],
+    names: &[&'a str],
+    offenses: &mut Vec<(TextRange, &'a str)>,
+) {
+    use ruff_python_ast::Stmt;
+
+    for stmt in body {
+        match stmt {
+            Stmt::Assign(assign) => {
+                for target in &assign.targets {
+                    record_target(target, names, stmt.range(), offenses);
+                }
+            }
+            Stmt::AugAssign(aug) => record_target(&aug.target, names, stmt.range(), offenses),
+            Stmt::AnnAssign(ann) => record_target(&ann.target, names, stmt.range(), offenses),
+            Stmt::Nonlocal(nonlocal) => {
+                for rebound in &nonlocal.names {
+                    if let Some(&name) = names.iter().find(|&&bound| bound == rebound.as_str()) {
+                        offenses.push((stmt.range(), name));
+                    }
+                }
+            }
+            Stmt::If(if_stmt) => {
+                collect_bound_mutations(&if_stmt.body, names, offenses);
+                for clause in &if_stmt.elif_else_clauses {
+                    collect_bound_mutations(&clause.body, names, offenses);
+                }
+            }
+            Stmt::For(for_stmt) => {
+                collect_bound_mutations(&for_stmt.body, names, offenses);
+                collect_bound_mutations(&for_stmt.orelse, names, offenses);
+            }
+            Stmt::While(while_stmt) => {
+                collect_bound_mutations(&while_stmt.body, names, offenses);
+                collect_bound_mutations(&while_stmt.orelse, names, offenses);
+            }
+            Stmt::With(with_stmt) => collect_bound_mutations(&with_stmt.body, names, offenses),
+            Stmt::Try(try_stmt) => {
+                collect_bound_mutations(&try_stmt.body, names, offenses);
+                for handler in &try_stmt.handlers {
+                    let ExceptHandler::ExceptHandler(handler) = handler;
+                    collect_bound_mutations(&handler.body, names, offenses);
+                }
+                collect_bound_mutations(&try_stmt.orelse, names, offenses);
+                collect_bound_mutations(&try_stmt.finalbody, names, offenses);
+            }
+            Stmt::Match(match_stmt) => {
+                for case in &match_stmt.cases {
+                    collect_bound_mutations(&case.body, names, offenses);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn record_target<'a>(
+    target: &Expr,
+    names: &[&'a str],
+    range: TextRange,
+    offenses: &mut Vec<(TextRange, &'a str)>,
+) {
+    match target {
+        Expr::Name(n) => {
+            if let Some(&name) = names.iter().find(|&&bound| bound == n.id.as_str()) {
+                offenses.push((range, name));
+            }
+        }
+        Expr::Tuple(tuple) => {
+            for elt in &tuple.elts {
+                record_target(elt, names, range, offenses);
+            }
+        }
+        Expr::List(list) => {
+            for elt in &list.elts {
+                record_target(elt, names, range, offenses);
+            }
+        }
+        Expr::Starred(starred) => record_target(&starred.value, names, range, offenses),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ruff_python_ast::Stmt;
+    use ruff_python_parser::parse_module;
+
+    /// Parses `source` (a single function definition), finds its first
+    /// `for` loop, and counts how many bound mutations it reports.
+    fn offenses(source: &str) -> usize {
+        let module = parse_module(source).unwrap();
+        let Stmt::FunctionDef(func) = &module.into_syntax().body[0] else {
+            panic!("expected a function definition");
+        };
+        let Stmt::For(for_stmt) = func
+            .body
+            .iter()
+            .find(|stmt| matches!(stmt, Stmt::For(_)))
+            .expect("expected a for loop in the function body")
+        else {
+            unreachable!()
+        };
+
+        let Some(bound_names) = range_bound_names(&for_stmt.iter) else {
+            return 0;
+        };
+        let mut offenses = Vec::new();
+        collect_bound_mutations(&for_stmt.body, &bound_names, &mut offenses);
+        offenses.len()
+    }
+
+    #[test]
+    fn reassigning_the_bound_is_flagged() {
+        let source = r#"
+def f():
+    stop = 10
+    for i in range(stop):
+        if found(i):
+            stop = i
+"#;
+        assert_eq!(offenses(source), 1);
+    }
+
+    #[test]
+    fn literal_bound_is_never_flagged() {
+        let source = r#"
+def f():
+    for i in range(10):
+        stop = i
+"#;
+        assert_eq!(offenses(source), 0);
+    }
+
+    #[test]
+    fn mutation_nested_in_try_finally_is_still_found() {
+        let source = r#"
+def f():
+    stop = 10
+    for i in range(stop):
+        try:
+            work(i)
+        finally:
+            stop = 0
+"#;
+        assert_eq!(offenses(source), 1);
+    }
+
+    #[test]
+    fn unrelated_name_is_not_flagged() {
+        let source = r#"
+def f():
+    stop = 10
+    for i in range(stop):
+        other = i
+"#;
+        assert_eq!(offenses(source), 0);
+    }
+}
@@ -0,0 +1,297 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::visitor::{walk_expr, Visitor};
+use ruff_python_ast::{Expr, ExprContext, StmtFor};
+use ruff_text_size::{Ranged, TextRange};
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `for` loops over `range(len(...))` where the loop variable
+/// indexes only that same sequence and is never reassigned.
+///
+/// ## Why is this bad?
+/// Iterating over the sequence directly (or pairing it with `enumerate()`
+/// if the index is also needed on its own) is more direct than re-deriving
+/// each element through `seq[i]`, and doesn't require the reader to check
+/// that `i` is never used for anything else.
+///
+/// ## Example
+/// ```python
+/// for i in range(len(fruits)):
+///     print(fruits[i])
+/// ```
+///
+/// Use instead:
+/// ```python
+/// for fruit in fruits:
+///     print(fruit)
+/// ```
+///
+/// Or, if the index is needed too:
+/// ```python
+/// for i, fruit in enumerate(fruits):
+///     print(i, fruit)
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct NeedlessRangeLoop {
+    index: String,
+    sequence: String,
+    needs_enumerate: bool,
+}
+
+impl Violation for NeedlessRangeLoop {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let NeedlessRangeLoop {
+            index,
+            sequence,
+            needs_enumerate,
+        } = self;
+        if *needs_enumerate {
+            format!(
+                "Use `enumerate({sequence})` instead of indexing `{sequence}` with `{index}` from `range(len(...))`"
+            )
+        } else {
+            format!("Iterate directly over `{sequence}` instead of indexing it with `{index}` from `range(len(...))`")
+        }
+    }
+}
+
+/// RUF303
+pub(crate) fn needless_range_loop(checker: &mut Checker, for_stmt: &StmtFor) {
+    let Some((index, sequence_name, usage)) = match_needless_range_loop(for_stmt) else {
+        return;
+    };
+
+    let mut diagnostic = Diagnostic::new(
+        NeedlessRangeLoop {
+            index: index.to_string(),
+            sequence: sequence_name.to_string(),
+            needs_enumerate: usage.bare_use,
+        },
+        for_stmt.iter.range(),
+    );
+
+    // The substitute name isn't checked against existing bindings, so it
+    // may shadow one; the fix is marked unsafe.
+    let item_name = singularize(sequence_name);
+    let header = if usage.bare_use {
+        format!("{index}, {item_name} in enumerate({sequence_name})")
+    } else {
+        format!("{item_name} in {sequence_name}")
+    };
+
+    let mut edits = vec![Edit::range_replacement(
+        header,
+        TextRange::new(for_stmt.target.range().start(), for_stmt.iter.range().end()),
+    )];
+    for subscript_range in &usage.subscripts {
+        edits.push(Edit::range_replacement(item_name.clone(), *subscript_range));
+    }
+
+    let fix = Fix::unsafe_edits(edits.remove(0), edits);
+    diagnostic.set_fix(fix);
+
+    checker.diagnostics.push(diagnostic);
+}
+
+/// Whether `for_stmt` is a `for i in range(len(seq))` loop whose only uses
+/// of `i` are subscripts into `seq` or bare reads of `i`. Returns the index
+/// name, the sequence name, and the collected usage (including whether `i`
+/// is also read on its own) when it qualifies. Factored out of [`needless_range_loop`] so it can be
+/// exercised directly in tests without the `Checker` plumbing that function
+/// needs.
+fn match_needless_range_loop<'a>(for_stmt: &'a StmtFor) -> Option<(&'a str, &'a str, IndexUsage<'a>)> {
+    let Expr::Name(index) = for_stmt.target.as_ref() else {
+        return None;
+    };
+    let sequence = match_range_len(&for_stmt.iter)?;
+    let Expr::Name(sequence_name) = sequence else {
+        return None;
+    };
+
+    let mut usage = IndexUsage {
+        index: index.id.as_str(),
+        sequence: sequence_name.id.as_str(),
+        reassigned: false,
+        other_collection: false,
+        bare_use: false,
+        subscripts: Vec::new(),
+    };
+    for stmt in &for_stmt.body {
+        usage.visit_stmt(stmt);
+    }
+
+    if usage.reassigned || usage.other_collection || usage.subscripts.is_empty() {
+        return None;
+    }
+
+    Some((index.id.as_str(), sequence_name.id.as_str(), usage))
+}
+
+/// Matches `range(len(seq))` or `range(0, len(seq))`, returning `seq`.
+fn match_range_len(iter: &Expr) -> Option<&Expr> {
+    let Expr::Call(call) = iter else { return None };
+    let Expr::Name(func) = call.func.as_ref() else {
+        return None;
+    };
+    if func.id.as_str() != "range" || !call.arguments.keywords.is_empty() {
+        return None;
+    }
+
+    let len_arg = match &*call.arguments.args {
+        [len_expr] => len_expr,
+        [start, len_expr] if is_zero(start) => len_expr,
+        _ => return None,
+    };
+
+    let Expr::Call(len_call) = len_arg else {
+        return None;
+    };
+    let Expr::Name(len_func) = len_call.func.as_ref() else {
+        return None;
+    };
+    if len_func.id.as_str() != "len" || !len_call.arguments.keywords.is_empty() {
+        return None;
+    }
+    match &*len_call.arguments.args {
+        [seq] => Some(seq),
+        _ => None,
+    }
+}
+
+fn is_zero(expr: &Expr) -> bool {
+    matches!(expr, Expr::NumberLiteral(lit) if lit.value.as_int().is_some_and(|n| n == 0))
+}
+
+/// A rough singular form of a plural collection name, for picking a loop
+/// variable name (`fruits` -> `fruit`). Falls back to `item` when there's
+/// no obvious singular form.
+fn singularize(name: &str) -> String {
+    name.strip_suffix('s')
+        .filter(|stem| !stem.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| "item".to_string())
+}
+
+struct IndexUsage<'a> {
+    index: &'a str,
+    sequence: &'a str,
+    reassigned: bool,
+    other_collection: bool,
+    bare_use: bool,
+    subscripts: Vec<TextRange>,
+}
+
+impl<'a> Visitor<'a> for IndexUsage<'a> {
+    fn visit_expr(&mut self, expr: &'a Expr) {
+        if let Expr::Subscript(subscript) = expr {
+            if is_name(&subscript.slice, self.index) {
+                if is_name(&subscript.value, self.sequence) {
+                    match subscript.ctx {
+                        ExprContext::Store | ExprContext::Del => {
+                            // `seq[i] = ...` (or `del seq[i]`) mutates `seq`
+                            // through the index; the rewrite drops `i`
+                            // entirely, so it would silently stop writing
+                            // back into `seq`. Bail out rather than fold
+                            // this in as an ordinary read.
+                            self.other_collection = true;
+                        }
+                        ExprContext::Load | ExprContext::Invalid => {
+                            self.subscripts.push(subscript.range());
+                        }
+                    }
+                } else {
+                    self.other_collection = true;
+                }
+                // The slice is just `index`, already classified above; only
+                // descend into the collection being indexed.
+                self.visit_expr(&subscript.value);
+                return;
+            }
+        }
+
+        if let Expr::Name(name) = expr {
+            if name.id.as_str() == self.index {
+                match name.ctx {
+                    ExprContext::Load => self.bare_use = true,
+                    ExprContext::Store | ExprContext::Del => self.reassigned = true,
+                    ExprContext::Invalid => {}
+                }
+                return;
+            }
+        }
+
+        walk_expr(self, expr);
+    }
+}
+
+fn is_name(expr: &Expr, name: &str) -> bool {
+    matches!(expr, Expr::Name(n) if n.id.as_str() == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ruff_python_ast::Stmt;
+    use ruff_python_parser::parse_module;
+
+    /// Parses `source` (a single function definition), finds its first
+    /// `for` loop, and runs [`match_needless_range_loop`] on it.
+    fn check(source: &str) -> bool {
+        let module = parse_module(source).unwrap();
+        let Stmt::FunctionDef(func) = &module.into_syntax().body[0] else {
+            panic!("expected a function definition");
+        };
+        let Stmt::For(for_stmt) = func
+            .body
+            .iter()
+            .find(|stmt| matches!(stmt, Stmt::For(_)))
+            .expect("expected a for loop in the function body")
+        else {
+            unreachable!()
+        };
+        match_needless_range_loop(for_stmt).is_some()
+    }
+
+    #[test]
+    fn pure_index_read_is_flagged() {
+        let source = r#"
+def f(fruits):
+    for i in range(len(fruits)):
+        print(fruits[i])
+"#;
+        assert!(check(source));
+    }
+
+    #[test]
+    fn mutating_through_the_index_is_not_flagged() {
+        let source = r#"
+def f(nums):
+    for i in range(len(nums)):
+        nums[i] = nums[i] * 2
+"#;
+        assert!(!check(source));
+    }
+
+    #[test]
+    fn indexing_a_different_sequence_is_not_flagged() {
+        let source = r#"
+def f(nums, other):
+    for i in range(len(nums)):
+        print(other[i])
+"#;
+        assert!(!check(source));
+    }
+
+    #[test]
+    fn bare_index_use_is_not_flagged() {
+        let source = r#"
+def f(nums):
+    for i in range(len(nums)):
+        print(i)
+"#;
+        assert!(!check(source));
+    }
+}
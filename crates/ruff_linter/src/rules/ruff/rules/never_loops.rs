@@ -1,9 +1,12 @@
+use std::collections::VecDeque;
+
 use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_index::Idx;
 use ruff_macros::{derive_message_formats, ViolationMetadata};
 use ruff_python_ast::Stmt;
 use ruff_python_semantic::cfg::{
     builder::{ControlEdge, ControlFlowGraph},
-    implementations::build_cfg,
+    implementations::{build_cfg, BlockId, CFG},
 };
 use ruff_text_size::Ranged;
 
@@ -52,26 +55,153 @@ impl Violation for NeverLoops {
 /// RUF300
 pub(crate) fn never_loops(checker: &mut Checker, body: &[Stmt]) {
     for (i, stmt) in body.iter().enumerate() {
-        match stmt {
-            Stmt::For(_) | Stmt::While(_) => {
-                let cfg = build_cfg(&body[i..i + 1]);
-                let loop_guard = cfg.out(cfg.initial()).targets().next().unwrap();
-                let loop_body = cfg.out(loop_guard).targets().next().unwrap();
-                if cfg
-                    .out(loop_body)
-                    .targets()
-                    .find(|tgt| tgt == &loop_guard)
-                    .is_none()
-                {
-                    dbg!(&cfg);
-                    checker
-                        .diagnostics
-                        .push(Diagnostic::new(NeverLoops, stmt.range()));
-                }
-            }
-            _ => {
-                continue;
+        if matches!(stmt, Stmt::For(_) | Stmt::While(_)) && loops_at_most_once(&body[i..i + 1]) {
+            checker
+                .diagnostics
+                .push(Diagnostic::new(NeverLoops, stmt.range()));
+        }
+    }
+}
+
+/// Whether `loop_stmt` (a single-element slice containing one `for` or
+/// `while` statement) can execute its body at most once -- i.e. no path
+/// through the body reaches the loop's back-edge (the guard). Factored out
+/// of [`never_loops`] so it can be exercised directly in tests without the
+/// `Checker` plumbing `never_loops` itself needs.
+fn loops_at_most_once(loop_stmt: &[Stmt]) -> bool {
+    let cfg = build_cfg(loop_stmt);
+    let loop_guard = cfg.outgoing(cfg.initial()).targets().next().unwrap();
+    // The guard's switch always lists the body before the `Else` exit (see
+    // the `Stmt::While`/`Stmt::For` builder arms), so the body is its first
+    // target.
+    let loop_body = cfg.outgoing(loop_guard).targets().next().unwrap();
+
+    !reaches(&cfg, loop_body, loop_guard)
+}
+
+/// Whether any path through the control-flow graph from `start` reaches
+/// `target`, following every outgoing edge regardless of its condition.
+///
+/// Used to ask "does some execution of the loop body reach the loop's
+/// back-edge (the guard), meaning it can run more than once?" A `continue`
+/// targeting this loop's guard reaches it, so the loop survives; a
+/// `break`/`return`/`raise` that jumps elsewhere (or a nested loop's own
+/// `break`/`continue`, which targets *its* guard/exit rather than this
+/// one) does not contribute a path back to `target` unless control falls
+/// back out of it and keeps going.
+fn reaches(cfg: &CFG<'_>, start: BlockId, target: BlockId) -> bool {
+    let mut seen = vec![false; cfg.num_blocks()];
+    let mut queue = VecDeque::from([start]);
+    seen[start.index()] = true;
+
+    while let Some(block) = queue.pop_front() {
+        if block == target {
+            return true;
+        }
+        for next in cfg.outgoing(block).targets() {
+            if !seen[next.index()] {
+                seen[next.index()] = true;
+                queue.push_back(next);
             }
         }
     }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ruff_python_parser::parse_module;
+
+    /// Parses `source` (a single function definition), finds its first
+    /// `for`/`while` loop, and runs [`loops_at_most_once`] on it.
+    fn check(source: &str) -> bool {
+        let module = parse_module(source).unwrap();
+        let Stmt::FunctionDef(func) = &module.into_syntax().body[0] else {
+            panic!("expected a function definition");
+        };
+        let index = func
+            .body
+            .iter()
+            .position(|stmt| matches!(stmt, Stmt::For(_) | Stmt::While(_)))
+            .expect("expected a for/while loop in the function body");
+        loops_at_most_once(&func.body[index..index + 1])
+    }
+
+    #[test]
+    fn try_except_with_continue_can_run_more_than_once() {
+        let source = r#"
+def f(items):
+    for item in items:
+        try:
+            risky(item)
+        except ValueError:
+            continue
+        log(item)
+"#;
+        assert!(!check(source));
+    }
+
+    #[test]
+    fn every_branch_returning_never_loops() {
+        let source = r#"
+def f(items):
+    for item in items:
+        if condition(item):
+            return 1
+        else:
+            return 2
+"#;
+        assert!(check(source));
+    }
+
+    #[test]
+    fn while_true_with_conditional_break_can_run_more_than_once() {
+        let source = r#"
+def f():
+    while True:
+        if should_stop():
+            break
+        work()
+"#;
+        assert!(!check(source));
+    }
+
+    #[test]
+    fn while_true_that_always_returns_never_loops() {
+        let source = r#"
+def f():
+    while True:
+        return 1
+"#;
+        assert!(check(source));
+    }
+
+    #[test]
+    fn nested_loop_lets_the_outer_loop_run_more_than_once() {
+        // The inner loop's own iterator can become exhausted (including on
+        // an empty iterable) without ever running its body, which sends
+        // control straight back to the outer loop's guard -- so the outer
+        // loop can run again no matter what the inner loop's body does.
+        let source = r#"
+def f(outer, inner):
+    for o in outer:
+        for i in inner:
+            return i
+"#;
+        assert!(!check(source));
+    }
+
+    #[test]
+    fn outer_loop_that_always_returns_before_any_nested_loop_never_loops() {
+        let source = r#"
+def f(outer, inner):
+    for o in outer:
+        return o
+        for i in inner:
+            pass
+"#;
+        assert!(check(source));
+    }
 }
@@ -0,0 +1,262 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_index::Idx;
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{Expr, Stmt};
+use ruff_python_semantic::cfg::{
+    builder::{Condition, ControlEdge, ControlFlowGraph},
+    implementations::{build_cfg, BlockId, CFG},
+};
+use ruff_text_size::{Ranged, TextRange};
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for statements that can never be executed.
+///
+/// ## Why is this bad?
+/// Unreachable code is almost always a mistake: a `return`/`raise`/
+/// `break`/`continue` earlier in the block that makes everything after it
+/// dead, an `else` clause attached to a branch that never falls through,
+/// or a condition (like `while False:`) whose body can never run. Dead
+/// code can't be exercised by tests and often signals that the author's
+/// intent doesn't match what the function actually does.
+///
+/// ## Example
+/// ```python
+/// def f():
+///     return 1
+///     print("never runs")
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct UnreachableCode;
+
+impl Violation for UnreachableCode {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "This statement is unreachable".to_string()
+    }
+}
+
+/// Dotted names of calls known to never return control to their caller.
+/// The CFG builder has no interprocedural knowledge of these, so it models
+/// a call to one of them like any other statement, with a normal
+/// fallthrough edge to whatever follows; we special-case them here instead
+/// so everything after one of these calls (within the same block, or in
+/// blocks only reachable through it) is reported as unreachable too.
+const NO_RETURN_CALLS: &[&[&str]] = &[
+    &["sys", "exit"],
+    &["os", "_exit"],
+    &["os", "abort"],
+    &["typing", "assert_never"],
+];
+
+/// RUF302
+pub(crate) fn unreachable_code(checker: &mut Checker, body: &[Stmt]) {
+    for range in find_unreachable(body) {
+        checker
+            .diagnostics
+            .push(Diagnostic::new(UnreachableCode, range));
+    }
+}
+
+/// Returns the ranges of every statement in `body` that can never execute.
+/// Factored out of [`unreachable_code`] so it can be exercised directly in
+/// tests without the `Checker` plumbing that function needs.
+fn find_unreachable(body: &[Stmt]) -> Vec<TextRange> {
+    let cfg = build_cfg(body);
+
+    // Walk forward from `initial()`, same as `ControlFlowGraph::reachable_blocks`,
+    // except we stop following a block's outgoing edges once we've passed a
+    // known no-return call, since nothing downstream of it can run, and we
+    // only follow the statically-live targets of a switch (e.g. the body of
+    // a `while False:`, or the `else` of an `if True:`, is never live) so
+    // those branches get reported as unreachable too. We deliberately don't
+    // build this on top of `with_folded_constants()`/`simplified()`: those
+    // prune dead blocks out of the graph entirely, which would erase the
+    // very statements we need to report.
+    let mut reachable = vec![false; cfg.num_blocks()];
+    let mut stack = vec![cfg.initial()];
+    reachable[cfg.initial().index()] = true;
+
+    while let Some(block) = stack.pop() {
+        if diverges_midway(&cfg.stmts(block).into_iter().collect::<Vec<_>>()).is_some() {
+            continue;
+        }
+        for target in live_targets(&cfg, block) {
+            if !reachable[target.index()] {
+                reachable[target.index()] = true;
+                stack.push(target);
+            }
+        }
+    }
+
+    let mut unreachable = Vec::new();
+    for index in 0..cfg.num_blocks() {
+        let block = BlockId::new(index);
+        let stmts: Vec<_> = cfg.stmts(block).into_iter().collect();
+
+        if !reachable[index] {
+            unreachable.extend(stmts.iter().map(|stmt| stmt.range()));
+            continue;
+        }
+
+        if let Some(cutoff) = diverges_midway(&stmts) {
+            unreachable.extend(stmts[cutoff + 1..].iter().map(|stmt| stmt.range()));
+        }
+    }
+    unreachable
+}
+
+/// The targets of `block`'s outgoing switch that can actually be taken.
+/// When every condition in the switch is a literal-foldable `Test` or an
+/// `Else` (e.g. `while False:`, `if True: ... else: ...`), only the first
+/// statically-true `Test` survives (falling back to `Else` if none are
+/// true), matching Python's left-to-right short-circuit evaluation.
+/// Anything else (a runtime-valued `Test`, a `match`/`for`/`except`
+/// switch, ...) is left alone and every target is considered live.
+fn live_targets(cfg: &CFG<'_>, block: BlockId) -> Vec<BlockId> {
+    let out = cfg.outgoing(block);
+    let pairs: Vec<_> = out.conditions().zip(out.targets()).collect();
+
+    let all_foldable = pairs.iter().all(|(condition, _)| match condition {
+        Condition::Test(expr) => static_truthiness(expr).is_some(),
+        Condition::Else => true,
+        _ => false,
+    });
+    if pairs.len() <= 1 || !all_foldable {
+        return pairs.into_iter().map(|(_, target)| target).collect();
+    }
+
+    let survivor = pairs
+        .iter()
+        .find(|(condition, _)| {
+            matches!(condition, Condition::Test(expr) if static_truthiness(expr) == Some(true))
+        })
+        .or_else(|| {
+            pairs
+                .iter()
+                .find(|(condition, _)| matches!(condition, Condition::Else))
+        });
+
+    survivor.map_or_else(Vec::new, |&(_, target)| vec![target])
+}
+
+/// Resolves the truthiness of an expression when it's a literal (or
+/// `__debug__`, which is always `True` unless Python is run with `-O`).
+/// Returns `None` if the expression's truthiness can't be determined
+/// without evaluating it.
+fn static_truthiness(expr: &Expr) -> Option<bool> {
+    match expr {
+        Expr::BooleanLiteral(lit) => Some(lit.value),
+        Expr::NoneLiteral(_) => Some(false),
+        Expr::Name(name) if name.id.as_str() == "__debug__" => Some(true),
+        _ => None,
+    }
+}
+
+/// If one of `stmts` is a recognized no-return call, returns the index of
+/// the *first* one, so callers can treat everything after it (in this
+/// block, and in any block only reachable through it) as unreachable.
+fn diverges_midway(stmts: &[&Stmt]) -> Option<usize> {
+    stmts.iter().position(|stmt| is_no_return_call(stmt))
+}
+
+fn is_no_return_call(stmt: &Stmt) -> bool {
+    let Stmt::Expr(expr_stmt) = stmt else {
+        return false;
+    };
+    let Expr::Call(call) = expr_stmt.value.as_ref() else {
+        return false;
+    };
+    let Some(segments) = dotted_name(&call.func) else {
+        return false;
+    };
+    NO_RETURN_CALLS
+        .iter()
+        .any(|path| path == &segments.as_slice())
+}
+
+/// Resolves a `Name` or `Attribute` chain (e.g. `sys.exit`) into its dotted
+/// path segments. Returns `None` for anything else (e.g. a call through a
+/// subscript or the result of another call), which we conservatively treat
+/// as potentially returning.
+fn dotted_name(expr: &Expr) -> Option<Vec<&str>> {
+    match expr {
+        Expr::Name(name) => Some(vec![name.id.as_str()]),
+        Expr::Attribute(attr) => {
+            let mut segments = dotted_name(&attr.value)?;
+            segments.push(attr.attr.as_str());
+            Some(segments)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ruff_python_parser::parse_module;
+
+    /// Parses `source` (a single function definition) and counts how many
+    /// statements in its body [`find_unreachable`] reports.
+    fn unreachable_count(source: &str) -> usize {
+        let module = parse_module(source).unwrap();
+        let Stmt::FunctionDef(func) = &module.into_syntax().body[0] else {
+            panic!("expected a function definition");
+        };
+        find_unreachable(&func.body).len()
+    }
+
+    #[test]
+    fn code_after_return_is_unreachable() {
+        let source = r#"
+def f():
+    return 1
+    print("never runs")
+"#;
+        assert_eq!(unreachable_count(source), 1);
+    }
+
+    #[test]
+    fn while_false_body_is_unreachable() {
+        let source = r#"
+def f():
+    while False:
+        print("never runs")
+"#;
+        assert_eq!(unreachable_count(source), 1);
+    }
+
+    #[test]
+    fn if_true_else_branch_is_unreachable() {
+        let source = r#"
+def f():
+    if True:
+        print("runs")
+    else:
+        print("never runs")
+"#;
+        assert_eq!(unreachable_count(source), 1);
+    }
+
+    #[test]
+    fn code_after_sys_exit_is_unreachable() {
+        let source = r#"
+def f():
+    import sys
+    sys.exit(1)
+    print("never runs")
+"#;
+        assert_eq!(unreachable_count(source), 1);
+    }
+
+    #[test]
+    fn ordinary_code_is_reachable() {
+        let source = r#"
+def f():
+    print("a")
+    print("b")
+"#;
+        assert_eq!(unreachable_count(source), 0);
+    }
+}
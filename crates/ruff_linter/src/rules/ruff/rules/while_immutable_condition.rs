@@ -0,0 +1,365 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_index::Idx;
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{Expr, ExceptHandler, Stmt};
+use ruff_python_semantic::cfg::{
+    builder::{ControlEdge, ControlFlowGraph},
+    implementations::{build_cfg, BlockId, CFG},
+};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `while` loops whose condition reads only names that are
+/// never changed inside the loop body, and that have no reachable `break`
+/// (or `return`/`raise`) to exit early.
+///
+/// ## Why is this bad?
+/// If nothing the condition depends on ever changes and there's no other
+/// way out, the loop can only ever run forever or not at all -- almost
+/// certainly not what was intended.
+///
+/// ## Example
+/// ```python
+/// done = False
+/// while not done:
+///     print("looping")
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct WhileImmutableCondition;
+
+impl Violation for WhileImmutableCondition {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "This `while` loop's condition never changes and the loop has no other exit; it may run forever".to_string()
+    }
+}
+
+/// RUF305
+pub(crate) fn while_immutable_condition(checker: &mut Checker, body: &[Stmt]) {
+    for (i, stmt) in body.iter().enumerate() {
+        let Stmt::While(_) = stmt else {
+            continue;
+        };
+
+        if may_run_forever(&body[i..i + 1]) {
+            checker
+                .diagnostics
+                .push(Diagnostic::new(WhileImmutableCondition, stmt.range()));
+        }
+    }
+}
+
+/// Whether `while_stmt` (a single-element slice containing one `while`
+/// statement) has a condition built only from names the body never
+/// mutates, and no reachable way to exit other than the guard itself.
+/// Factored out of [`while_immutable_condition`] so it can be exercised
+/// directly in tests without the `Checker` plumbing that function needs.
+fn may_run_forever(stmt_slice: &[Stmt]) -> bool {
+    let [Stmt::While(while_stmt)] = stmt_slice else {
+        return false;
+    };
+
+    // Conservative: a condition that calls a function or reads through
+    // an attribute/subscript might depend on state we can't trace, so
+    // we only handle conditions built from names, literals, and
+    // boolean/comparison/arithmetic operators over them.
+    let mut names = Vec::new();
+    if condition_names(&while_stmt.test, &mut names).is_none() || names.is_empty() {
+        return false;
+    }
+
+    if names
+        .iter()
+        .any(|name| body_mutates(&while_stmt.body, name))
+    {
+        return false;
+    }
+
+    // Build a CFG for just this loop and ask whether the body can reach
+    // the function's terminal -- a `break`, a `return`, or an uncaught
+    // `raise` -- *without* going back through the guard. That last part
+    // matters: the guard's own "condition is now false" edge always
+    // exists in the graph (structurally, a CFG doesn't know the
+    // condition's names never change), and for a loop with an `else`
+    // clause that edge leads to the `else` body and on to the same
+    // terminal a `break` would reach. Reaching terminal by routing back
+    // through the guard isn't a real way out -- since nothing in the
+    // body mutates the condition, the guard can only ever re-evaluate
+    // to the same thing -- so we exclude the guard from the search and
+    // look for a path that leaves the loop some other way.
+    let cfg = build_cfg(stmt_slice);
+    let loop_guard = cfg.outgoing(cfg.initial()).targets().next().unwrap();
+    let loop_body = cfg.outgoing(loop_guard).targets().next().unwrap();
+
+    !reaches_without(&cfg, loop_body, cfg.terminal(), loop_guard)
+}
+
+/// Whether `target` is reachable from `start`, without ever passing
+/// through `avoid`.
+fn reaches_without(cfg: &CFG<'_>, start: BlockId, target: BlockId, avoid: BlockId) -> bool {
+    if start == avoid {
+        return false;
+    }
+
+    let mut seen = vec![false; cfg.num_blocks()];
+    let mut stack = vec![start];
+    seen[start.index()] = true;
+
+    while let Some(block) = stack.pop() {
+        if block == target {
+            return true;
+        }
+        for next in cfg.outgoing(block).targets() {
+            if next == avoid || seen[next.index()] {
+                continue;
+            }
+            seen[next.index()] = true;
+            stack.push(next);
+        }
+    }
+
+    false
+}
+
+/// Collects the plain names read in `expr`, returning `None` if `expr`
+/// contains anything (a call, an attribute access, a subscript, ...) whose
+/// mutation we can't trace.
+fn condition_names<'a>(expr: &'a Expr, names: &mut Vec<&'a str>) -> Option<()> {
+    match expr {
+        Expr::Name(name) => {
+            if !names.contains(&name.id.as_str()) {
+                names.push(name.id.as_str());
+            }
+            Some(())
+        }
+        Expr::BoolOp(b) => b
+            .values
+            .iter()
+            .try_for_each(|value| condition_names(value, names)),
+        Expr::BinOp(b) => {
+            condition_names(&b.left, names)?;
+            condition_names(&b.right, names)
+        }
+        Expr::UnaryOp(u) => condition_names(&u.operand, names),
+        Expr::Compare(c) => {
+            condition_names(&c.left, names)?;
+            c.comparators
+                .iter()
+                .try_for_each(|cmp| condition_names(cmp, names))
+        }
+        Expr::BooleanLiteral(_)
+        | Expr::NumberLiteral(_)
+        | Expr::NoneLiteral(_)
+        | Expr::StringLiteral(_) => Some(()),
+        _ => None,
+    }
+}
+
+fn body_mutates(body: &[Stmt], name: &str) -> bool {
+    body.iter().any(|stmt| stmt_mutates(stmt, name))
+}
+
+fn stmt_mutates(stmt: &Stmt, name: &str) -> bool {
+    match stmt {
+        Stmt::Assign(assign) => {
+            assign.targets.iter().any(|t| target_mutates(t, name))
+                || expr_mutates(&assign.value, name)
+        }
+        Stmt::AugAssign(aug) => {
+            target_mutates(&aug.target, name) || expr_mutates(&aug.value, name)
+        }
+        Stmt::AnnAssign(ann) => {
+            target_mutates(&ann.target, name)
+                || ann.value.as_deref().is_some_and(|v| expr_mutates(v, name))
+        }
+        Stmt::Global(global) => global.names.iter().any(|n| n.as_str() == name),
+        Stmt::Nonlocal(nonlocal) => nonlocal.names.iter().any(|n| n.as_str() == name),
+        Stmt::Delete(delete) => delete.targets.iter().any(|t| target_mutates(t, name)),
+        Stmt::For(for_stmt) => {
+            target_mutates(&for_stmt.target, name)
+                || expr_mutates(&for_stmt.iter, name)
+                || body_mutates(&for_stmt.body, name)
+                || body_mutates(&for_stmt.orelse, name)
+        }
+        Stmt::While(while_stmt) => {
+            expr_mutates(&while_stmt.test, name)
+                || body_mutates(&while_stmt.body, name)
+                || body_mutates(&while_stmt.orelse, name)
+        }
+        Stmt::If(if_stmt) => {
+            expr_mutates(&if_stmt.test, name)
+                || body_mutates(&if_stmt.body, name)
+                || if_stmt.elif_else_clauses.iter().any(|clause| {
+                    clause
+                        .test
+                        .as_ref()
+                        .is_some_and(|test| expr_mutates(test, name))
+                        || body_mutates(&clause.body, name)
+                })
+        }
+        Stmt::With(with_stmt) => {
+            with_stmt.items.iter().any(|item| {
+                expr_mutates(&item.context_expr, name)
+                    || item
+                        .optional_vars
+                        .as_deref()
+                        .is_some_and(|target| target_mutates(target, name))
+            }) || body_mutates(&with_stmt.body, name)
+        }
+        Stmt::Try(try_stmt) => {
+            body_mutates(&try_stmt.body, name)
+                || try_stmt.handlers.iter().any(|handler| {
+                    let ExceptHandler::ExceptHandler(handler) = handler;
+                    handler.name.as_ref().is_some_and(|n| n.as_str() == name)
+                        || body_mutates(&handler.body, name)
+                })
+                || body_mutates(&try_stmt.orelse, name)
+                || body_mutates(&try_stmt.finalbody, name)
+        }
+        Stmt::Match(match_stmt) => {
+            expr_mutates(&match_stmt.subject, name)
+                || match_stmt
+                    .cases
+                    .iter()
+                    .any(|case| body_mutates(&case.body, name))
+        }
+        Stmt::Expr(expr_stmt) => expr_mutates(&expr_stmt.value, name),
+        Stmt::Return(ret) => ret.value.as_ref().is_some_and(|v| expr_mutates(v, name)),
+        Stmt::Assert(assert_stmt) => {
+            expr_mutates(&assert_stmt.test, name)
+                || assert_stmt
+                    .msg
+                    .as_ref()
+                    .is_some_and(|msg| expr_mutates(msg, name))
+        }
+        // A nested function/class could close over and rebind `name` via
+        // `nonlocal`; conservatively assume it might.
+        Stmt::FunctionDef(_) | Stmt::ClassDef(_) => true,
+        _ => false,
+    }
+}
+
+fn target_mutates(target: &Expr, name: &str) -> bool {
+    match target {
+        Expr::Name(n) => n.id.as_str() == name,
+        Expr::Attribute(attr) => is_name(&attr.value, name),
+        Expr::Subscript(sub) => is_name(&sub.value, name),
+        Expr::Tuple(tuple) => tuple.elts.iter().any(|e| target_mutates(e, name)),
+        Expr::List(list) => list.elts.iter().any(|e| target_mutates(e, name)),
+        Expr::Starred(starred) => target_mutates(&starred.value, name),
+        _ => false,
+    }
+}
+
+fn is_name(expr: &Expr, name: &str) -> bool {
+    matches!(expr, Expr::Name(n) if n.id.as_str() == name)
+}
+
+/// Whether `expr` reads `name` in a way we can't prove is side-effect-free:
+/// called directly, passed as a call argument, or used as the receiver of
+/// a method call. Each might mutate whatever `name` refers to even though
+/// `name` itself is never reassigned.
+fn expr_mutates(expr: &Expr, name: &str) -> bool {
+    match expr {
+        Expr::Call(call) => {
+            if is_name(&call.func, name) {
+                return true;
+            }
+            if let Expr::Attribute(attr) = call.func.as_ref() {
+                if is_name(&attr.value, name) {
+                    return true;
+                }
+            }
+            call.arguments
+                .args
+                .iter()
+                .any(|arg| is_name(arg, name) || expr_mutates(arg, name))
+                || call
+                    .arguments
+                    .keywords
+                    .iter()
+                    .any(|kw| is_name(&kw.value, name) || expr_mutates(&kw.value, name))
+                || expr_mutates(&call.func, name)
+        }
+        Expr::BoolOp(b) => b.values.iter().any(|v| expr_mutates(v, name)),
+        Expr::BinOp(b) => expr_mutates(&b.left, name) || expr_mutates(&b.right, name),
+        Expr::UnaryOp(u) => expr_mutates(&u.operand, name),
+        Expr::Compare(c) => {
+            expr_mutates(&c.left, name) || c.comparators.iter().any(|cmp| expr_mutates(cmp, name))
+        }
+        Expr::Attribute(attr) => expr_mutates(&attr.value, name),
+        Expr::Subscript(sub) => expr_mutates(&sub.value, name) || expr_mutates(&sub.slice, name),
+        Expr::Tuple(t) => t.elts.iter().any(|e| expr_mutates(e, name)),
+        Expr::List(l) => l.elts.iter().any(|e| expr_mutates(e, name)),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ruff_python_parser::parse_module;
+
+    /// Parses `source` (a single function definition), finds its first
+    /// `while` loop, and runs [`may_run_forever`] on it.
+    fn check(source: &str) -> bool {
+        let module = parse_module(source).unwrap();
+        let Stmt::FunctionDef(func) = &module.into_syntax().body[0] else {
+            panic!("expected a function definition");
+        };
+        let index = func
+            .body
+            .iter()
+            .position(|stmt| matches!(stmt, Stmt::While(_)))
+            .expect("expected a while loop in the function body");
+        may_run_forever(&func.body[index..index + 1])
+    }
+
+    #[test]
+    fn untouched_condition_with_no_break_may_run_forever() {
+        let source = r#"
+def f():
+    done = False
+    while not done:
+        print("looping")
+"#;
+        assert!(check(source));
+    }
+
+    #[test]
+    fn body_mutating_the_condition_is_fine() {
+        let source = r#"
+def f():
+    done = False
+    while not done:
+        done = True
+"#;
+        assert!(!check(source));
+    }
+
+    #[test]
+    fn break_with_an_else_clause_is_a_real_exit() {
+        let source = r#"
+def f():
+    done = False
+    while not done:
+        if check():
+            break
+    else:
+        print("no break")
+"#;
+        assert!(!check(source));
+    }
+
+    #[test]
+    fn call_in_condition_is_not_flagged() {
+        let source = r#"
+def f():
+    while still_running():
+        pass
+"#;
+        assert!(!check(source));
+    }
+}
@@ -24,6 +24,47 @@ pub trait ControlFlowGraph<'stmt> {
         &self,
         block: Self::Block,
     ) -> impl IntoIterator<Item = Self::Block> + ExactSizeIterator;
+
+    /// Numbers every block reachable from `initial()` in reverse
+    /// postorder, via an explicit-stack DFS over `outgoing` targets (so we
+    /// don't blow the stack on deeply nested or long functions).
+    fn reverse_postorder(&self) -> Vec<Self::Block>
+    where
+        Self::Block: PartialEq,
+    {
+        let mut visited: Vec<Self::Block> = Vec::with_capacity(self.num_blocks());
+        let mut postorder = Vec::with_capacity(self.num_blocks());
+
+        let initial = self.initial();
+        let mut stack = vec![(initial, 0usize)];
+        visited.push(initial);
+
+        while let Some((block, next)) = stack.pop() {
+            let targets: Vec<_> = self.outgoing(block).targets().collect();
+            if let Some(&successor) = targets.get(next) {
+                stack.push((block, next + 1));
+                if !visited.contains(&successor) {
+                    visited.push(successor);
+                    stack.push((successor, 0));
+                }
+            } else {
+                postorder.push(block);
+            }
+        }
+
+        postorder.reverse();
+        postorder
+    }
+
+    /// Every block reachable from `initial()`, in reverse-postorder. Blocks
+    /// that are never reached (e.g. dead code after an unconditional
+    /// `return`) are omitted.
+    fn reachable_blocks(&self) -> Vec<Self::Block>
+    where
+        Self::Block: PartialEq,
+    {
+        self.reverse_postorder()
+    }
 }
 
 /// Represents a condition to be tested in a multi-way branch
@@ -46,6 +87,11 @@ pub enum Condition<'stmt> {
     ExceptHandler(&'stmt ExceptHandlerExceptHandler),
     /// An uncaught exception
     UncaughtException,
+    /// An in-flight exception that a `with` statement's `__exit__` (or
+    /// `contextlib.suppress`) swallowed by returning a truthy value,
+    /// letting control resume after the `with` block instead of
+    /// propagating to an enclosing handler or the terminal.
+    ExceptionSuppressed,
     /// A fallback case (else/wildcard case/etc.)
     Else,
     /// Unconditional edge
@@ -129,10 +175,24 @@ pub trait CFGBuilder<'stmt> {
 
     /// Creates a new block to handle dispatching control flow at the end
     /// of a `try` block.
-    fn new_exception_dispatch(&mut self) -> Self::BasicBlock;
+    fn new_exception_dispatch(&mut self, is_star: bool) -> Self::BasicBlock;
 
     fn new_recovery(&mut self) -> Self::BasicBlock;
 
+    /// Pushes the exit block of a `with` (or `async with`) statement we're
+    /// about to enter the body of.
+    fn push_with_exit(&mut self, exit: Self::BasicBlock);
+
+    /// Pops the exit block pushed by the matching `push_with_exit`.
+    fn pop_with_exit(&mut self) -> Option<Self::BasicBlock>;
+
+    /// The exit blocks of every `with` statement we're currently inside,
+    /// innermost last. A `raise` (or other exception-raising point) within
+    /// a `with` body may, in addition to its normal target, flow to the
+    /// innermost of these via `Condition::ExceptionSuppressed`, modeling
+    /// `__exit__` (or `contextlib.suppress`) swallowing the exception.
+    fn with_exits(&self) -> Vec<Self::BasicBlock>;
+
     /// Adds an outgoing edge from the current block to the target specified in the edge.
     fn add_edge(&mut self, edge: Self::Edge);
 
@@ -164,6 +224,32 @@ pub trait CFGBuilder<'stmt> {
                 | Stmt::Delete(_)
                 | Stmt::IpyEscapeCommand(_) => {
                     self.push_stmt(stmt);
+
+                    // Any one of these statements could itself raise, not
+                    // just the last one in the body, so (when
+                    // `split_try_body` is enabled) split the block and give
+                    // it its own edge into the enclosing `try`'s dispatch,
+                    // instead of only the one implicit edge at the natural
+                    // end of the body. If we're also inside a `with`, give
+                    // the raise a chance to flow to the `with`'s exit via
+                    // `Condition::ExceptionSuppressed` too, modeling
+                    // `__exit__` swallowing it -- the same control flow
+                    // `with` would have if it were desugared into a
+                    // `try`/`finally`.
+                    let suppressed = self.with_exits().last().copied();
+                    let dispatch = self.current_dispatch_target();
+                    if self.split_try_body() && (dispatch.is_some() || suppressed.is_some()) {
+                        let propagate = dispatch.unwrap_or_else(|| self.terminal());
+                        let next = self.new_block();
+                        let mut conditions =
+                            vec![(Condition::Always, next), (Condition::UncaughtException, propagate)];
+                        if let Some(suppressed) = suppressed {
+                            conditions.push((Condition::ExceptionSuppressed, suppressed));
+                        }
+                        let edge = Self::Edge::switch(conditions);
+                        self.add_edge(edge);
+                        self.move_to(next);
+                    }
                 }
                 // Loops
                 Stmt::While(stmt_while) => {
@@ -412,10 +498,31 @@ pub trait CFGBuilder<'stmt> {
                     let edge = Self::Edge::switch(conditions);
                     self.add_edge(edge);
 
-                    // Process each case's body
+                    // Note: `match` itself is not a breakable scope in
+                    // Python -- a `break`/`continue` inside a case body
+                    // always targets the nearest enclosing loop, never the
+                    // match statement, so we don't push a block scope here.
+                    // (`push_block_scope` exists for constructs that do
+                    // introduce their own `break`-only exit.)
+
+                    // Process each case's body. A case with a guard (`case
+                    // pattern if cond:`) only actually matches if the guard
+                    // is truthy, so give it an extra switch edge: `cond`
+                    // leads into the body, and a failed guard leads to
+                    // `next_block`, after the whole `match`. A failed guard
+                    // has no edge to the later cases.
                     for (case, block) in case_blocks {
                         self.move_to(block);
                         self.update_exit(next_block);
+                        if let Some(guard) = &case.guard {
+                            let body_block = self.new_block();
+                            let edge = Self::Edge::switch(vec![
+                                (Condition::Test(guard), body_block),
+                                (Condition::Else, next_block),
+                            ]);
+                            self.add_edge(edge);
+                            self.move_to(body_block);
+                        }
                         self.process_stmts(&case.body);
                     }
 
@@ -462,7 +569,7 @@ pub trait CFGBuilder<'stmt> {
                         }
                     };
 
-                    self.push_try_context(try_kind);
+                    self.push_try_context(try_kind, stmt_try.is_star);
                     let try_block = self.new_try_block();
                     if self.current() != try_block {
                         self.add_edge(Self::Edge::always(try_block));
@@ -494,9 +601,11 @@ pub trait CFGBuilder<'stmt> {
                             self.resolve_deferred_jumps();
                         }
                         TryKind::TryExcept => {
-                            let dispatch_block = self.new_exception_dispatch();
+                            let dispatch_block = self.new_exception_dispatch(stmt_try.is_star);
                             self.update_exit(dispatch_block);
+                            self.push_dispatch_target(dispatch_block);
                             self.process_stmts(&stmt_try.body);
+                            self.pop_dispatch_target();
 
                             self.move_to(dispatch_block);
                             self.set_try_state(TryState::Dispatch);
@@ -523,17 +632,15 @@ pub trait CFGBuilder<'stmt> {
                             self.add_edge(edge);
                             // Process each case's body
                             self.set_try_state(TryState::Except);
-                            for (handler, block) in except_blocks {
-                                self.move_to(block);
-                                self.update_exit(next_block);
-                                self.process_stmts(&handler.body);
-                            }
+                            self.process_except_handlers(except_blocks, stmt_try.is_star, next_block);
                             self.pop_try_context();
                         }
                         TryKind::TryExceptElse => {
-                            let dispatch_block = self.new_exception_dispatch();
+                            let dispatch_block = self.new_exception_dispatch(stmt_try.is_star);
                             self.update_exit(dispatch_block);
+                            self.push_dispatch_target(dispatch_block);
                             self.process_stmts(&stmt_try.body);
+                            self.pop_dispatch_target();
 
                             self.move_to(dispatch_block);
                             self.set_try_state(TryState::Dispatch);
@@ -562,11 +669,7 @@ pub trait CFGBuilder<'stmt> {
                             self.add_edge(edge);
                             // Process each case's body
                             self.set_try_state(TryState::Except);
-                            for (handler, block) in except_blocks {
-                                self.move_to(block);
-                                self.update_exit(next_block);
-                                self.process_stmts(&handler.body);
-                            }
+                            self.process_except_handlers(except_blocks, stmt_try.is_star, next_block);
                             // Process else body
                             self.set_try_state(TryState::Else);
                             self.move_to(else_block);
@@ -574,12 +677,14 @@ pub trait CFGBuilder<'stmt> {
                             self.pop_try_context();
                         }
                         TryKind::TryExceptFinally => {
-                            let dispatch_block = self.new_exception_dispatch();
+                            let dispatch_block = self.new_exception_dispatch(stmt_try.is_star);
                             let finally_block = self.new_block();
                             let recovery_block = self.new_recovery();
 
                             self.update_exit(dispatch_block);
+                            self.push_dispatch_target(dispatch_block);
                             self.process_stmts(&stmt_try.body);
+                            self.pop_dispatch_target();
 
                             self.move_to(dispatch_block);
                             self.set_try_state(TryState::Dispatch);
@@ -605,11 +710,7 @@ pub trait CFGBuilder<'stmt> {
                             self.add_edge(edge);
                             // Process each case's body
                             self.set_try_state(TryState::Except);
-                            for (handler, block) in except_blocks {
-                                self.move_to(block);
-                                self.update_exit(finally_block);
-                                self.process_stmts(&handler.body);
-                            }
+                            self.process_except_handlers(except_blocks, stmt_try.is_star, finally_block);
 
                             // Process finally clause
                             self.move_to(finally_block);
@@ -624,12 +725,14 @@ pub trait CFGBuilder<'stmt> {
                             self.resolve_deferred_jumps();
                         }
                         TryKind::TryExceptElseFinally => {
-                            let dispatch_block = self.new_exception_dispatch();
+                            let dispatch_block = self.new_exception_dispatch(stmt_try.is_star);
                             let finally_block = self.new_block();
                             let recovery_block = self.new_recovery();
 
                             self.update_exit(dispatch_block);
+                            self.push_dispatch_target(dispatch_block);
                             self.process_stmts(&stmt_try.body);
+                            self.pop_dispatch_target();
 
                             self.move_to(dispatch_block);
                             self.set_try_state(TryState::Dispatch);
@@ -658,13 +761,10 @@ pub trait CFGBuilder<'stmt> {
 
                             // Process each case's body
                             self.set_try_state(TryState::Except);
-                            self.update_exit(finally_block);
-                            for (handler, block) in except_blocks {
-                                self.move_to(block);
-                                self.process_stmts(&handler.body);
-                            }
+                            self.process_except_handlers(except_blocks, stmt_try.is_star, finally_block);
 
                             // Process else body
+                            self.update_exit(finally_block);
                             self.move_to(else_block);
                             self.set_try_state(TryState::Else);
                             self.process_stmts(&stmt_try.orelse);
@@ -689,8 +789,28 @@ pub trait CFGBuilder<'stmt> {
                     // Continue from next_block
                     self.move_to(next_block);
                 }
-                Stmt::With(_) => {
-                    self.push_stmt(stmt);
+                Stmt::With(stmt_with) => {
+                    // Create a new block for any following statements
+                    let next_block = self.next_or_exit(&mut stmts);
+
+                    let body_block = self.new_block();
+                    self.add_edge(Self::Edge::always(body_block));
+
+                    let old_exit = self.current_exit();
+                    self.move_to(body_block);
+                    self.update_exit(next_block);
+
+                    // A `__exit__` that returns truthy (as `contextlib.suppress`
+                    // does) can swallow an in-flight exception, letting flow
+                    // resume right after the `with` instead of propagating.
+                    // Exception-raising points within the body can target
+                    // `next_block` via `Condition::ExceptionSuppressed`.
+                    self.push_with_exit(next_block);
+                    self.process_stmts(&stmt_with.body);
+                    self.pop_with_exit();
+
+                    self.update_exit(old_exit);
+                    self.move_to(next_block);
                 }
 
                 // Jumps
@@ -723,9 +843,33 @@ pub trait CFGBuilder<'stmt> {
                     }
                 }
 
-                // TODO
                 Stmt::Raise(_) => {
                     self.push_stmt(stmt);
+                    if self.should_defer_jumps() {
+                        self.push_deferred_jump(stmt);
+                    } else {
+                        // Outside of any `try`, an uncaught raise just
+                        // leaves the function; inside one, it goes to the
+                        // nearest enclosing handler's dispatch block. If
+                        // we're inside a `with`, its `__exit__` also gets a
+                        // chance to suppress the exception.
+                        let target = self
+                            .current_dispatch_target()
+                            .unwrap_or_else(|| self.terminal());
+                        let edge = match self.with_exits().last().copied() {
+                            Some(suppressed) => Self::Edge::switch(vec![
+                                (Condition::UncaughtException, target),
+                                (Condition::ExceptionSuppressed, suppressed),
+                            ]),
+                            None => Self::Edge::always(target),
+                        };
+                        self.add_edge(edge);
+                    }
+
+                    if stmts.peek().is_some() {
+                        let next_block = self.new_block();
+                        self.move_to(next_block);
+                    }
                 }
 
                 Stmt::Continue(_) => {
@@ -742,11 +886,43 @@ pub trait CFGBuilder<'stmt> {
                         self.move_to(next_block);
                     }
                 }
-                // Assert is sort of a mixture of a switch and a jump,
-                // so handled as such
-                // TODO
-                Stmt::Assert(_) => {
-                    self.push_stmt(stmt);
+                // Assert is sort of a mixture of a switch and a jump: `assert
+                // cond` behaves like `if not cond: raise AssertionError`, so
+                // it gets a `Test` edge to the rest of the body and an
+                // exceptional edge (to the enclosing dispatch, a
+                // `with`'s `__exit__`, or the function's exit) when the
+                // assertion fails.
+                Stmt::Assert(stmt_assert) => {
+                    let next_block = self.next_or_exit(&mut stmts);
+                    let fail_block = self.new_block();
+
+                    let edge = Self::Edge::switch(vec![
+                        (Condition::Test(&stmt_assert.test), next_block),
+                        (Condition::Else, fail_block),
+                    ]);
+                    self.add_edge(edge);
+
+                    self.move_to(fail_block);
+                    if self.should_defer_jumps() {
+                        // A failing assertion raises just like `raise`, so
+                        // inside a `try` it must run the `finally` before
+                        // actually leaving -- defer it the same way.
+                        self.push_deferred_jump(stmt);
+                    } else {
+                        let target = self
+                            .current_dispatch_target()
+                            .unwrap_or_else(|| self.terminal());
+                        let raise_edge = match self.with_exits().last().copied() {
+                            Some(suppressed) => Self::Edge::switch(vec![
+                                (Condition::UncaughtException, target),
+                                (Condition::ExceptionSuppressed, suppressed),
+                            ]),
+                            None => Self::Edge::always(target),
+                        };
+                        self.add_edge(raise_edge);
+                    }
+
+                    self.move_to(next_block);
                 }
             }
             // Restore exit
@@ -764,11 +940,65 @@ pub trait CFGBuilder<'stmt> {
         }
     }
 
+    /// Processes each `except`/`except*` handler's body. Plain `except`
+    /// handlers are mutually exclusive, so each just exits straight to
+    /// `join`. `except*` (exception-group) handlers are additive instead:
+    /// Python 3.11 can run *multiple* `except*` clauses against a single
+    /// raised `ExceptionGroup`, with control falling through from one
+    /// starred handler into the next rather than being routed around it.
+    /// To model that, each starred handler gets a dedicated exit block
+    /// with an edge to both the next handler and the shared `join` block.
+    fn process_except_handlers(
+        &mut self,
+        except_blocks: Vec<(&'stmt ExceptHandlerExceptHandler, Self::BasicBlock)>,
+        is_star: bool,
+        join: Self::BasicBlock,
+    ) {
+        for (i, (handler, block)) in except_blocks.iter().enumerate() {
+            self.move_to(*block);
+            if is_star {
+                let handler_exit = self.new_block();
+                self.update_exit(handler_exit);
+                self.process_stmts(&handler.body);
+
+                self.move_to(handler_exit);
+                let edge = match except_blocks.get(i + 1) {
+                    Some(&(_, next_handler)) => Self::Edge::switch(vec![
+                        (Condition::Always, next_handler),
+                        (Condition::Always, join),
+                    ]),
+                    None => Self::Edge::always(join),
+                };
+                self.add_edge(edge);
+            } else {
+                self.update_exit(join);
+                self.process_stmts(&handler.body);
+            }
+        }
+    }
+
     fn new_try_block(&mut self) -> Self::BasicBlock;
 
-    /// Returns the current loop exit block without removing it.
+    /// Whether a statement inside a `try`/`with` body other than the last
+    /// gets its own edge into the enclosing dispatch/exit, instead of only
+    /// an edge at the natural end of the block. Off by default, matching
+    /// the coarser-grained graph existing consumers were built against;
+    /// enable it with [`set_split_try_body`](Self::set_split_try_body) for
+    /// analyses that need to pin an exception to a specific statement.
+    fn split_try_body(&self) -> bool {
+        false
+    }
+
+    /// Toggles [`split_try_body`](Self::split_try_body) for this builder.
+    fn set_split_try_body(&mut self, enabled: bool);
+
+    /// Returns the `break` target of the innermost breakable scope -- a
+    /// loop (pushed by [`push_loop`](Self::push_loop)) or a plain block
+    /// scope (pushed by [`push_block_scope`](Self::push_block_scope)),
+    /// whichever is nearer.
     fn loop_exit(&self) -> Self::BasicBlock;
-    /// Returns the current loop guard block without removing it.
+    /// Returns the `continue` target of the innermost loop, skipping past
+    /// any plain block scopes nested inside it.
     fn loop_guard(&self) -> Self::BasicBlock;
 
     /// Pushes a block onto the loop exit stack.
@@ -780,11 +1010,32 @@ pub trait CFGBuilder<'stmt> {
     /// This is called when finishing the processing of a loop construct.
     fn pop_loop(&mut self) -> Option<(Self::BasicBlock, Self::BasicBlock)>;
 
-    fn push_try_context(&mut self, kind: TryKind);
-    fn try_contexts(&self) -> &Vec<TryContext>;
+    /// Pushes a plain breakable scope (no continue target) onto the same
+    /// stack `push_loop` uses. No arm of `process_stmts` pushes one (a
+    /// `match` statement is not a breakable scope).
+    /// `break` resolves against the innermost breakable scope of either
+    /// kind; `continue` still only matches a [`push_loop`](Self::push_loop)
+    /// scope, so it correctly skips past one of these to the enclosing
+    /// loop.
+    fn push_block_scope(&mut self, exit: Self::BasicBlock);
+
+    /// Pops and returns the most recently pushed breakable-block scope.
+    fn pop_block_scope(&mut self) -> Option<Self::BasicBlock>;
+
+    fn push_try_context(&mut self, kind: TryKind, is_star: bool);
+    fn try_contexts(&self) -> Vec<&TryContext<'stmt>>;
     fn last_try_context(&self) -> Option<&TryContext<'stmt>>;
     fn last_mut_try_context(&mut self) -> Option<&mut TryContext<'stmt>>;
     fn pop_try_context(&mut self) -> Option<TryContext<'stmt>>;
+
+    /// Pushes the exception-dispatch block that a raise from inside the
+    /// `try` body currently being processed should route to.
+    fn push_dispatch_target(&mut self, dispatch: Self::BasicBlock);
+    /// Pops the dispatch target pushed by [`push_dispatch_target`](Self::push_dispatch_target).
+    fn pop_dispatch_target(&mut self) -> Option<Self::BasicBlock>;
+    /// The innermost active dispatch target, if any statement currently
+    /// being processed is directly inside a `try` body with handlers.
+    fn current_dispatch_target(&self) -> Option<Self::BasicBlock>;
     fn set_try_state(&mut self, state: TryState) {
         if let Some(ctxt) = self.last_mut_try_context() {
             ctxt.state = state;
@@ -827,6 +1078,14 @@ pub trait CFGBuilder<'stmt> {
                 Stmt::Return(_) => (Condition::Deferred(stmt), self.terminal()),
                 Stmt::Break(_) => (Condition::Deferred(stmt), self.loop_exit()),
                 Stmt::Continue(_) => (Condition::Deferred(stmt), self.loop_guard()),
+                Stmt::Raise(_) => (
+                    Condition::Deferred(stmt),
+                    self.current_dispatch_target().unwrap_or_else(|| self.terminal()),
+                ),
+                Stmt::Assert(_) => (
+                    Condition::Deferred(stmt),
+                    self.current_dispatch_target().unwrap_or_else(|| self.terminal()),
+                ),
                 _ => {
                     todo!()
                 }
@@ -862,18 +1121,26 @@ pub enum TryState {
 pub struct TryContext<'stmt> {
     kind: TryKind,
     state: TryState,
+    /// Whether this is a `try`/`except*` (exception-group) statement,
+    /// whose handlers are additive rather than mutually exclusive.
+    is_star: bool,
     deferred_jumps: Vec<&'stmt Stmt>,
 }
 
 impl<'stmt> TryContext<'stmt> {
-    pub fn new(kind: TryKind) -> Self {
+    pub fn new(kind: TryKind, is_star: bool) -> Self {
         Self {
             kind,
             state: TryState::Try,
+            is_star,
             deferred_jumps: Vec::new(),
         }
     }
 
+    pub fn is_star(&self) -> bool {
+        self.is_star
+    }
+
     fn has_except(&self) -> bool {
         matches!(
             self.kind,
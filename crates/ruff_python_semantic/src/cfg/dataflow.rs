@@ -0,0 +1,180 @@
+//! A generic, monotone dataflow-analysis framework over [`ControlFlowGraph`].
+//!
+//! Analyses like liveness, reaching definitions, or a per-block type
+//! lattice (e.g. `Unknown`/`Nil`/`Bool`/`Int`/`Str` refinements flowing
+//! along edges) only need to describe their [`Analysis::Domain`] and
+//! [`Analysis::transfer_block`]; [`solve`] drives the worklist fixpoint
+//! across the graph so each analysis doesn't have to re-walk blocks ad
+//! hoc, in either direction, for any [`ControlFlowGraph`] implementor.
+//!
+//! Predecessor lookups for the backward direction go through the same
+//! [`Predecessors`](super::visualize::Predecessors) trait that
+//! [`scc`](super::scc) uses, rather than reaching for `ControlFlowGraph`'s
+//! own `predecessors` method directly.
+//!
+//! This is the foundation reachability, definite-assignment, and
+//! live-variable lints are meant to build on: each of those is just a
+//! [`Lattice`] plus an [`Analysis`] impl fed to [`solve`], rather than its
+//! own bespoke fixpoint loop. There's deliberately no separate
+//! `State<V>`/`apply_stmt`/`apply_edge` engine alongside this one --
+//! `Lattice`/`Analysis`/`solve` already generalize over exactly that shape
+//! (a monotone join-semilattice transferred across blocks and edges), and a
+//! second competing framework would just be two ways to do the same thing.
+//! A reachability analysis here is `Lattice = bool` (`join` = `||`) with a
+//! `transfer_block` that's the identity; a definite-assignment analysis is
+//! `Lattice = IndexVec<Var, bool>` with intersection as `join`.
+
+use std::collections::VecDeque;
+
+use ruff_index::{Idx, IndexVec};
+
+use super::builder::{Condition, ControlFlowGraph};
+use super::visualize::Predecessors;
+
+/// Which way an [`Analysis`] flows across the CFG.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// State flows from `initial()` towards `terminal()`, e.g. reaching
+    /// definitions.
+    Forward,
+    /// State flows from `terminal()` towards `initial()`, e.g. liveness.
+    Backward,
+}
+
+/// A join-semilattice: a domain with a least element and a way to merge
+/// two values together.
+pub trait Lattice: Eq {
+    /// The least element of the lattice.
+    fn bottom() -> Self;
+
+    /// Merges `other` into `self`, returning whether `self` changed as a
+    /// result. Must be monotone (merging can only move `self` up the
+    /// lattice) for [`solve`] to be guaranteed to terminate.
+    fn join(&mut self, other: &Self) -> bool;
+}
+
+/// A monotone dataflow analysis over a [`ControlFlowGraph`].
+pub trait Analysis<'stmt, G: ControlFlowGraph<'stmt>> {
+    /// The lattice value tracked at each block boundary.
+    type Domain: Lattice + Clone;
+
+    /// Which way the analysis flows.
+    const DIRECTION: Direction;
+
+    /// The state flowing into the entry block (forward) or out of the
+    /// exit block (backward), before any block has been processed.
+    /// Defaults to [`Lattice::bottom`].
+    fn initial_state(&self) -> Self::Domain {
+        Self::Domain::bottom()
+    }
+
+    /// Applies the effect of a block's statements to `state`, in place.
+    fn transfer_block(&self, block: G::Block, state: &mut Self::Domain);
+
+    /// Refines `state` for a single outgoing (forward) edge, so that a
+    /// [`Condition`] can narrow the domain differently per successor, e.g.
+    /// narrowing a type lattice on the true vs. false edge of a `Test`.
+    /// Defaults to a no-op. Only consulted when solving forward, since a
+    /// backward walk doesn't know which outgoing edge of a predecessor led
+    /// to the block it's processing.
+    fn transfer_edge(&self, _condition: &Condition<'stmt>, _state: &mut Self::Domain) {}
+}
+
+/// The dataflow state computed for every block: what's true on entry to
+/// the block, and what's true on exit from it.
+#[derive(Debug)]
+pub struct AnalysisResult<B: Idx, D> {
+    pub entry: IndexVec<B, D>,
+    pub exit: IndexVec<B, D>,
+}
+
+/// Runs `analysis` over `graph` to a fixpoint, using a worklist seeded in
+/// reverse postorder (forward analyses) or postorder (backward analyses)
+/// for fast convergence, and returns the per-block in/out states.
+pub fn solve<'stmt, G, A>(graph: &G, analysis: &A) -> AnalysisResult<G::Block, A::Domain>
+where
+    G: ControlFlowGraph<'stmt> + Predecessors<'stmt>,
+    G::Block: Idx,
+    A: Analysis<'stmt, G>,
+{
+    let num_blocks = graph.num_blocks();
+    let forward = A::DIRECTION == Direction::Forward;
+
+    let mut order = reverse_postorder(graph);
+    if !forward {
+        order.reverse();
+    }
+
+    let mut entry: IndexVec<G::Block, A::Domain> =
+        IndexVec::from_fn_n(|_| A::Domain::bottom(), num_blocks);
+    let mut exit: IndexVec<G::Block, A::Domain> =
+        IndexVec::from_fn_n(|_| A::Domain::bottom(), num_blocks);
+
+    if forward {
+        entry[graph.initial()] = analysis.initial_state();
+    } else {
+        exit[graph.terminal()] = analysis.initial_state();
+    }
+
+    let mut worklist: VecDeque<G::Block> = order.into_iter().collect();
+
+    while let Some(block) = worklist.pop_front() {
+        if forward {
+            let mut state = entry[block].clone();
+            analysis.transfer_block(block, &mut state);
+            exit[block] = state.clone();
+
+            let out = graph.outgoing(block);
+            for (condition, successor) in out.conditions().zip(out.targets()) {
+                let mut refined = state.clone();
+                analysis.transfer_edge(&condition, &mut refined);
+                if entry[successor].join(&refined) {
+                    worklist.push_back(successor);
+                }
+            }
+        } else {
+            let mut state = exit[block].clone();
+            analysis.transfer_block(block, &mut state);
+            entry[block] = state.clone();
+
+            for predecessor in Predecessors::predecessors(graph, block) {
+                if exit[predecessor].join(&state) {
+                    worklist.push_back(predecessor);
+                }
+            }
+        }
+    }
+
+    AnalysisResult { entry, exit }
+}
+
+/// Numbers every block reachable from `initial()` in reverse postorder,
+/// via an explicit-stack DFS over `outgoing` targets.
+fn reverse_postorder<'stmt, G>(graph: &G) -> Vec<G::Block>
+where
+    G: ControlFlowGraph<'stmt>,
+    G::Block: Idx,
+{
+    let mut visited = vec![false; graph.num_blocks()];
+    let mut postorder = Vec::with_capacity(graph.num_blocks());
+
+    let initial = graph.initial();
+    let mut stack = vec![(initial, 0usize)];
+    visited[initial.index()] = true;
+
+    while let Some((block, next)) = stack.pop() {
+        let targets: Vec<_> = graph.outgoing(block).targets().collect();
+        if let Some(&successor) = targets.get(next) {
+            stack.push((block, next + 1));
+            if !visited[successor.index()] {
+                visited[successor.index()] = true;
+                stack.push((successor, 0));
+            }
+        } else {
+            postorder.push(block);
+        }
+    }
+
+    postorder.reverse();
+    postorder
+}
@@ -0,0 +1,123 @@
+//! Dominator-tree computation over any [`StartNode`] + [`Successors`]
+//! graph, not just [`CFG`](super::implementations::CFG). Sharing the
+//! algorithm at this level lets it run over e.g.
+//! [`CFGWithSource`](super::visualize::CFGWithSource) for free.
+
+use ruff_index::{Idx, IndexVec};
+
+use super::visualize::{DirectedGraph, StartNode, Successors};
+
+/// A graph whose immediate-dominator tree can be computed.
+pub trait Dominators<'a>: StartNode<'a> + Successors<'a> {
+    /// Computes the immediate dominator of every node, using the iterative
+    /// Cooper-Harvey-Kennedy algorithm. Nodes unreachable from
+    /// `start_node()` are mapped to a sentinel `Node` that doesn't
+    /// correspond to any real node in the graph.
+    fn dominators(&self) -> IndexVec<Self::Node, Self::Node> {
+        compute_dominators(self)
+    }
+}
+
+impl<'a, T: StartNode<'a> + Successors<'a>> Dominators<'a> for T {}
+
+fn compute_dominators<'a, T>(graph: &T) -> IndexVec<T::Node, T::Node>
+where
+    T: StartNode<'a> + Successors<'a> + ?Sized,
+{
+    let num_nodes = graph.num_nodes();
+    let sentinel = T::Node::new(num_nodes);
+    let start = graph.start_node();
+
+    // This trait only exposes `successors`, so build the reverse adjacency
+    // list we need for the dominance computation ourselves.
+    let mut predecessors: Vec<Vec<T::Node>> = vec![Vec::new(); num_nodes];
+    for index in 0..num_nodes {
+        let node = T::Node::new(index);
+        for successor in graph.successors(node) {
+            predecessors[successor.index()].push(node);
+        }
+    }
+
+    let rpo = reverse_postorder(graph);
+    let mut rpo_number: Vec<Option<usize>> = vec![None; num_nodes];
+    for (number, &node) in rpo.iter().enumerate() {
+        rpo_number[node.index()] = Some(number);
+    }
+
+    let mut idom: IndexVec<T::Node, T::Node> = IndexVec::from_elem_n(sentinel, num_nodes);
+    idom[start] = start;
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for &node in rpo.iter().skip(1) {
+            let mut new_idom = None;
+            for &pred in &predecessors[node.index()] {
+                if idom[pred] == sentinel {
+                    continue;
+                }
+                new_idom = Some(match new_idom {
+                    None => pred,
+                    Some(current) => intersect(&idom, &rpo_number, current, pred),
+                });
+            }
+            if let Some(new_idom) = new_idom {
+                if idom[node] != new_idom {
+                    idom[node] = new_idom;
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    idom
+}
+
+/// Walks two fingers up the dominator tree, advancing whichever finger has
+/// the larger reverse-postorder number, until they meet.
+fn intersect<N: Idx>(
+    idom: &IndexVec<N, N>,
+    rpo_number: &[Option<usize>],
+    mut a: N,
+    mut b: N,
+) -> N {
+    while a != b {
+        while rpo_number[a.index()] > rpo_number[b.index()] {
+            a = idom[a];
+        }
+        while rpo_number[b.index()] > rpo_number[a.index()] {
+            b = idom[b];
+        }
+    }
+    a
+}
+
+/// Numbers every node reachable from `start_node()` in reverse postorder,
+/// via an explicit-stack DFS over `successors`.
+fn reverse_postorder<'a, T>(graph: &T) -> Vec<T::Node>
+where
+    T: StartNode<'a> + Successors<'a> + ?Sized,
+{
+    let mut visited = vec![false; graph.num_nodes()];
+    let mut postorder = Vec::with_capacity(graph.num_nodes());
+
+    let start = graph.start_node();
+    let mut stack = vec![(start, 0usize)];
+    visited[start.index()] = true;
+
+    while let Some((node, next)) = stack.pop() {
+        let successors = graph.successors(node);
+        if let Some(&successor) = successors.get(next) {
+            stack.push((node, next + 1));
+            if !visited[successor.index()] {
+                visited[successor.index()] = true;
+                stack.push((successor, 0));
+            }
+        } else {
+            postorder.push(node);
+        }
+    }
+
+    postorder.reverse();
+    postorder
+}
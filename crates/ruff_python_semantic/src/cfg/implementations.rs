@@ -1,6 +1,6 @@
 use crate::cfg::builder::{CFGBuilder, Condition, ControlEdge, ControlFlowGraph, TryContext};
 use ruff_index::{newtype_index, IndexVec};
-use ruff_python_ast::Stmt;
+use ruff_python_ast::{Expr, Stmt};
 
 use super::builder::TryKind;
 
@@ -10,6 +10,19 @@ pub fn build_cfg(stmts: &[Stmt]) -> CFG<'_> {
     builder.build()
 }
 
+/// Like [`build_cfg`], but gives every statement inside a `try`/`with`
+/// body (other than the last) its own edge into the enclosing
+/// dispatch/exit, instead of only the implicit edge at the end of the
+/// block. This is a strictly finer-grained graph -- more blocks, more
+/// edges -- useful for analyses that need to pin a possible exception to
+/// a specific statement rather than just "somewhere in this body".
+pub fn build_cfg_with_split_try_body(stmts: &[Stmt]) -> CFG<'_> {
+    let mut builder = CFGConstructor::with_capacity(stmts.len());
+    builder.set_split_try_body(true);
+    builder.process_stmts(stmts);
+    builder.build()
+}
+
 #[newtype_index]
 pub struct BlockId;
 
@@ -46,17 +59,20 @@ impl<'stmt> ControlEdge<'stmt> for NextBlock<'stmt> {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub enum BlockKind {
     #[default]
     Generic,
     LoopGuard,
-    ExceptionDispatch,
+    /// Dispatches a raised exception to the matching `except` handler(s).
+    /// `is_star` records whether this is a `try`/`except*` dispatch, whose
+    /// handlers are additive rather than mutually exclusive.
+    ExceptionDispatch { is_star: bool },
     Recovery,
     Terminal,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 struct BlockData<'stmt> {
     kind: BlockKind,
     stmts: Vec<&'stmt Stmt>,
@@ -75,6 +91,238 @@ impl<'stmt> CFG<'stmt> {
     pub fn kind(&self, block: BlockId) -> &BlockKind {
         &self.blocks[block].kind
     }
+
+    /// Computes, for every block, its immediate dominator: the unique
+    /// closest block that every path from `initial()` must pass through
+    /// before reaching it. Downstream analyses (unreachable code, possibly
+    /// unbound variables, definite assignment) can use this to ask "does
+    /// block A always execute before block B?" by walking the dominator
+    /// chain from B up to the root.
+    ///
+    /// Uses the iterative Cooper-Harvey-Kennedy algorithm, via the
+    /// graph-generic implementation in [`dominators`](super::dominators)
+    /// (so the same algorithm also works over e.g. `CFGWithSource`).
+    /// Blocks that cannot be reached from `initial()` are mapped to a
+    /// sentinel `BlockId` that doesn't correspond to any real block.
+    pub fn dominators(&self) -> IndexVec<BlockId, BlockId> {
+        use super::dominators::Dominators;
+        Dominators::dominators(self)
+    }
+
+    /// Builds a copy of this CFG with statically-resolvable branches (e.g.
+    /// `if True:`, `while False:`, `if __debug__:`) collapsed down to a
+    /// single `always` edge to the surviving target, unconditional jumps
+    /// threaded through empty pass-through blocks, and any block that's no
+    /// longer reachable from `initial()` dropped entirely.
+    ///
+    /// The original CFG is left untouched, so callers that want the raw,
+    /// unsimplified graph (e.g. for visualization) can keep using it.
+    pub fn with_folded_constants(&self) -> CFG<'stmt> {
+        let mut blocks = self.blocks.clone();
+
+        // Collapse switches whose surviving edge we can determine statically.
+        for block in blocks.indices() {
+            let out = blocks[block].out.clone();
+            if out.targets.len() <= 1 {
+                continue;
+            }
+            let Some(survivor) = Self::resolve_switch(&out) else {
+                continue;
+            };
+            for &stale in &out.targets {
+                if stale != survivor {
+                    if let Some(pos) = blocks[stale].parents.iter().position(|&p| p == block) {
+                        blocks[stale].parents.remove(pos);
+                    }
+                }
+            }
+            blocks[block].out = NextBlock {
+                conditions: vec![Condition::Always],
+                targets: vec![survivor],
+            };
+        }
+
+        Self::thread_and_prune(blocks, self.initial, self.terminal)
+    }
+
+    /// Builds a copy of this CFG with unconditional jumps threaded through
+    /// empty pass-through blocks (e.g. the join block after an `if` whose
+    /// branches both fall through) and any block that's not reachable from
+    /// `initial()` dropped entirely.
+    ///
+    /// Unlike [`with_folded_constants`](Self::with_folded_constants), this
+    /// doesn't try to resolve statically-foldable branches -- it only cleans
+    /// up blocks that were already redundant in the graph the builder
+    /// produced. The original CFG is left untouched, so callers that want
+    /// the raw, unsimplified graph (e.g. for visualization) can keep using
+    /// it.
+    pub fn simplified(&self) -> CFG<'stmt> {
+        Self::thread_and_prune(self.blocks.clone(), self.initial, self.terminal)
+    }
+
+    /// Threads unconditional jumps through empty, [`BlockKind::Generic`]
+    /// pass-through blocks to a fixpoint, then drops every block left
+    /// unreachable from `initial` (other than `terminal`, which is always
+    /// kept), renumbering the survivors.
+    fn thread_and_prune(
+        mut blocks: IndexVec<BlockId, BlockData<'stmt>>,
+        initial: BlockId,
+        terminal: BlockId,
+    ) -> CFG<'stmt> {
+        // Thread unconditional jumps through empty blocks that do nothing
+        // but forward control, e.g. the join block after an `if` whose
+        // branches both fall through. Iterate to a fixpoint since threading
+        // one jump can expose another chained one right behind it.
+        loop {
+            let mut changed = false;
+            for block in blocks.indices() {
+                let Some(target) = Self::sole_always_target(&blocks[block].out) else {
+                    continue;
+                };
+                if target == block {
+                    continue;
+                }
+                let Some(next) = Self::sole_always_target(&blocks[target].out) else {
+                    continue;
+                };
+                if !blocks[target].stmts.is_empty()
+                    || !matches!(blocks[target].kind, BlockKind::Generic)
+                    || next == target
+                {
+                    continue;
+                }
+                if let Some(pos) = blocks[target].parents.iter().position(|&p| p == block) {
+                    blocks[target].parents.remove(pos);
+                }
+                if !blocks[next].parents.contains(&block) {
+                    blocks[next].parents.push(block);
+                }
+                blocks[block].out = NextBlock {
+                    conditions: vec![Condition::Always],
+                    targets: vec![next],
+                };
+                changed = true;
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        // Find everything reachable from `initial` over the (now simplified)
+        // outgoing edges. `terminal` is always kept, even if unreachable,
+        // since callers rely on it being a valid block.
+        let mut reachable = vec![false; blocks.len()];
+        let mut stack = vec![initial];
+        reachable[initial.index()] = true;
+        while let Some(block) = stack.pop() {
+            for target in blocks[block].out.targets() {
+                if !reachable[target.index()] {
+                    reachable[target.index()] = true;
+                    stack.push(target);
+                }
+            }
+        }
+        reachable[terminal.index()] = true;
+
+        // Renumber the surviving blocks, dropping the rest.
+        let mut remap: IndexVec<BlockId, Option<BlockId>> =
+            IndexVec::from_elem_n(None, blocks.len());
+        let mut new_blocks: IndexVec<BlockId, BlockData<'stmt>> =
+            IndexVec::with_capacity(reachable.iter().filter(|&&keep| keep).count());
+        for (index, &keep) in reachable.iter().enumerate() {
+            if keep {
+                remap[BlockId::new(index)] = Some(new_blocks.push(BlockData::default()));
+            }
+        }
+        for (index, &keep) in reachable.iter().enumerate() {
+            if !keep {
+                continue;
+            }
+            let old = BlockId::new(index);
+            let new = remap[old].expect("block was marked reachable above");
+            let data = &blocks[old];
+            new_blocks[new] = BlockData {
+                kind: data.kind.clone(),
+                stmts: data.stmts.clone(),
+                out: NextBlock {
+                    conditions: data.out.conditions.clone(),
+                    targets: data
+                        .out
+                        .targets
+                        .iter()
+                        .map(|&target| remap[target].expect("live edges only target reachable blocks"))
+                        .collect(),
+                },
+                parents: data
+                    .parents
+                    .iter()
+                    .filter_map(|&parent| remap[parent])
+                    .collect(),
+            };
+        }
+
+        CFG {
+            blocks: new_blocks,
+            initial: remap[initial].expect("initial block is always reachable"),
+            terminal: remap[terminal].expect("terminal block is always kept"),
+        }
+    }
+
+    /// Returns `target` if `out` is a single unconditional edge to it.
+    fn sole_always_target(out: &NextBlock<'stmt>) -> Option<BlockId> {
+        match (out.conditions.as_slice(), out.targets.as_slice()) {
+            ([Condition::Always], [target]) => Some(*target),
+            _ => None,
+        }
+    }
+
+    /// If a switch's surviving target can be determined statically (i.e.
+    /// every `Test` condition up to and including the first statically
+    /// true one has a literal, constant-foldable truthiness), returns that
+    /// target, or the `Else` target if no `Test` is true. Otherwise returns
+    /// `None`, leaving the switch as-is.
+    fn resolve_switch(out: &NextBlock<'stmt>) -> Option<BlockId> {
+        let mut survivor = None;
+        for (condition, &target) in out.conditions.iter().zip(out.targets.iter()) {
+            match condition {
+                // Python short-circuits an if/elif chain at the first
+                // branch whose condition is true, so the first statically
+                // true `Test` wins -- any later ones are unreachable and
+                // must not overwrite it.
+                Condition::Test(expr) => match Self::static_truthiness(expr) {
+                    Some(true) => {
+                        survivor = Some(target);
+                        break;
+                    }
+                    Some(false) => {}
+                    None => return None,
+                },
+                Condition::Else => {}
+                // `match`/`for`/`except` switches aren't literal-foldable.
+                _ => return None,
+            }
+        }
+        survivor.or_else(|| {
+            out.conditions
+                .iter()
+                .zip(out.targets.iter())
+                .find(|(condition, _)| matches!(condition, Condition::Else))
+                .map(|(_, &target)| target)
+        })
+    }
+
+    /// Resolves the truthiness of an expression when it's a literal (or
+    /// `__debug__`, which is always `True` unless Python is run with `-O`).
+    /// Returns `None` if the expression's truthiness can't be determined
+    /// without evaluating it.
+    fn static_truthiness(expr: &Expr) -> Option<bool> {
+        match expr {
+            Expr::BooleanLiteral(lit) => Some(lit.value),
+            Expr::NoneLiteral(_) => Some(false),
+            Expr::Name(name) if name.id.as_str() == "__debug__" => Some(true),
+            _ => None,
+        }
+    }
 }
 
 impl<'stmt> ControlFlowGraph<'stmt> for CFG<'stmt> {
@@ -121,13 +369,35 @@ impl LoopContext {
     }
 }
 
+/// The different kinds of scope a statement being processed can be nested
+/// inside: a loop (for `break`/`continue`), a plain breakable block (for
+/// `break` only; `match` does not push one), a `try` (for
+/// exceptions and deferred jumps), or a `with` body or exception dispatch
+/// (both of which just track a single target block). Unifying these onto
+/// one stack means the statements that care about enclosing scope (jumps,
+/// raises) only need to walk one stack from the top, in the order the
+/// scopes actually nest, rather than reconciling several stacks that could
+/// otherwise get out of sync with each other.
+///
+/// `Loop` and `Block` together form rustc's notion of a "breakable scope":
+/// `break` resolves against the innermost of either, while `continue` only
+/// ever matches a `Loop`.
+#[derive(Debug, Clone)]
+enum FlowControl<'stmt> {
+    Loop(LoopContext),
+    Block(BlockId),
+    Try(TryContext<'stmt>),
+    With(BlockId),
+    Dispatch(BlockId),
+}
+
 #[derive(Debug)]
 pub struct CFGConstructor<'stmt> {
     cfg: CFG<'stmt>,
     current: BlockId,
     current_exit: BlockId,
-    loop_contexts: Vec<LoopContext>,
-    try_contexts: Vec<TryContext<'stmt>>,
+    scopes: Vec<FlowControl<'stmt>>,
+    split_try_body: bool,
 }
 
 impl<'stmt> CFGBuilder<'stmt> for CFGConstructor<'stmt> {
@@ -155,8 +425,8 @@ impl<'stmt> CFGBuilder<'stmt> for CFGConstructor<'stmt> {
             },
             current: initial,
             current_exit: terminal,
-            loop_contexts: Vec::new(),
-            try_contexts: Vec::new(),
+            scopes: Vec::new(),
+            split_try_body: false,
         }
     }
 
@@ -208,10 +478,15 @@ impl<'stmt> CFGBuilder<'stmt> for CFGConstructor<'stmt> {
     }
 
     fn loop_exit(&self) -> Self::BasicBlock {
-        self.loop_contexts
-            .last()
-            .expect("Syntax error to have `break` or `continue` outside of a loop")
-            .exit
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| match scope {
+                FlowControl::Loop(ctxt) => Some(ctxt.exit),
+                FlowControl::Block(exit) => Some(*exit),
+                _ => None,
+            })
+            .expect("Syntax error to have `break` outside of a loop or breakable block")
     }
 
     fn build(self) -> Self::Graph {
@@ -229,27 +504,54 @@ impl<'stmt> CFGBuilder<'stmt> for CFGConstructor<'stmt> {
         self.cfg.outgoing(block)
     }
 
-    fn new_exception_dispatch(&mut self) -> Self::BasicBlock {
+    fn new_exception_dispatch(&mut self, is_star: bool) -> Self::BasicBlock {
         self.cfg.blocks.push(BlockData {
-            kind: BlockKind::ExceptionDispatch, // New kind
+            kind: BlockKind::ExceptionDispatch { is_star },
             ..BlockData::default()
         })
     }
 
-    fn push_try_context(&mut self, kind: TryKind) {
-        self.try_contexts.push(TryContext::new(kind));
+    fn push_try_context(&mut self, kind: TryKind, is_star: bool) {
+        self.scopes
+            .push(FlowControl::Try(TryContext::new(kind, is_star)));
+    }
+
+    fn try_contexts(&self) -> Vec<&TryContext<'stmt>> {
+        self.scopes
+            .iter()
+            .filter_map(|scope| match scope {
+                FlowControl::Try(ctxt) => Some(ctxt),
+                _ => None,
+            })
+            .collect()
     }
 
     fn last_try_context(&self) -> Option<&TryContext<'stmt>> {
-        self.try_contexts.last()
+        self.scopes.iter().rev().find_map(|scope| match scope {
+            FlowControl::Try(ctxt) => Some(ctxt),
+            _ => None,
+        })
     }
 
     fn last_mut_try_context(&mut self) -> Option<&mut TryContext<'stmt>> {
-        self.try_contexts.last_mut()
+        self.scopes.iter_mut().rev().find_map(|scope| match scope {
+            FlowControl::Try(ctxt) => Some(ctxt),
+            _ => None,
+        })
     }
 
     fn pop_try_context(&mut self) -> Option<TryContext<'stmt>> {
-        self.try_contexts.pop()
+        match self.scopes.pop() {
+            Some(FlowControl::Try(ctxt)) => Some(ctxt),
+            // Scopes are always pushed/popped in strict LIFO order by the
+            // matching `process_stmts` arm, so this shouldn't happen; put
+            // the scope back rather than silently dropping it.
+            Some(other) => {
+                self.scopes.push(other);
+                None
+            }
+            None => None,
+        }
     }
 
     fn new_recovery(&mut self) -> Self::BasicBlock {
@@ -259,22 +561,107 @@ impl<'stmt> CFGBuilder<'stmt> for CFGConstructor<'stmt> {
         })
     }
 
+    fn push_dispatch_target(&mut self, dispatch: Self::BasicBlock) {
+        self.scopes.push(FlowControl::Dispatch(dispatch));
+    }
+
+    fn pop_dispatch_target(&mut self) -> Option<Self::BasicBlock> {
+        match self.scopes.pop() {
+            Some(FlowControl::Dispatch(dispatch)) => Some(dispatch),
+            Some(other) => {
+                self.scopes.push(other);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn current_dispatch_target(&self) -> Option<Self::BasicBlock> {
+        self.scopes.iter().rev().find_map(|scope| match scope {
+            FlowControl::Dispatch(dispatch) => Some(*dispatch),
+            _ => None,
+        })
+    }
+
+    fn push_with_exit(&mut self, exit: Self::BasicBlock) {
+        self.scopes.push(FlowControl::With(exit));
+    }
+
+    fn pop_with_exit(&mut self) -> Option<Self::BasicBlock> {
+        match self.scopes.pop() {
+            Some(FlowControl::With(exit)) => Some(exit),
+            Some(other) => {
+                self.scopes.push(other);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn with_exits(&self) -> Vec<Self::BasicBlock> {
+        self.scopes
+            .iter()
+            .filter_map(|scope| match scope {
+                FlowControl::With(exit) => Some(*exit),
+                _ => None,
+            })
+            .collect()
+    }
+
     fn loop_guard(&self) -> Self::BasicBlock {
-        self.loop_contexts
-            .last()
+        self.innermost_loop()
             .expect("Must be inside loop for `continue`.")
             .guard
     }
 
     fn push_loop(&mut self, guard: Self::BasicBlock, exit: Self::BasicBlock) {
-        self.loop_contexts.push(LoopContext::new(guard, exit));
+        self.scopes
+            .push(FlowControl::Loop(LoopContext::new(guard, exit)));
     }
 
     fn pop_loop(&mut self) -> Option<(Self::BasicBlock, Self::BasicBlock)> {
-        let Some(ctxt) = self.loop_contexts.pop() else {
-            return None;
-        };
-        Some((ctxt.guard, ctxt.exit))
+        match self.scopes.pop() {
+            Some(FlowControl::Loop(ctxt)) => Some((ctxt.guard, ctxt.exit)),
+            Some(other) => {
+                self.scopes.push(other);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn push_block_scope(&mut self, exit: Self::BasicBlock) {
+        self.scopes.push(FlowControl::Block(exit));
+    }
+
+    fn pop_block_scope(&mut self) -> Option<Self::BasicBlock> {
+        match self.scopes.pop() {
+            Some(FlowControl::Block(exit)) => Some(exit),
+            Some(other) => {
+                self.scopes.push(other);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn split_try_body(&self) -> bool {
+        self.split_try_body
+    }
+
+    fn set_split_try_body(&mut self, enabled: bool) {
+        self.split_try_body = enabled;
+    }
+}
+
+impl<'stmt> CFGConstructor<'stmt> {
+    /// The nearest enclosing loop, skipping over any `try`/`with`/dispatch
+    /// scopes nested inside it.
+    fn innermost_loop(&self) -> Option<&LoopContext> {
+        self.scopes.iter().rev().find_map(|scope| match scope {
+            FlowControl::Loop(ctxt) => Some(ctxt),
+            _ => None,
+        })
     }
 }
 
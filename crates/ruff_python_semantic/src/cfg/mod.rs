@@ -174,7 +174,10 @@
 //! I guess there could be some context about being unreachable
 //! or something...? Or maybe no special casing is required? Unclear.
 pub mod builder;
+pub mod dataflow;
+pub mod dominators;
 pub mod implementations;
+pub mod scc;
 pub mod visualize;
 
 #[cfg(test)]
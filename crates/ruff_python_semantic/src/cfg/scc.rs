@@ -0,0 +1,128 @@
+//! Strongly-connected-component (SCC) analysis over any
+//! [`DirectedGraph`] + [`Successors`] graph.
+//!
+//! A non-trivial SCC (more than one node, or a single node with a
+//! self-loop) is exactly a loop in the control-flow graph. An SCC entered
+//! from more than one of its own nodes is *irreducible*: there's no
+//! single loop header that dominates the rest of the loop body, which
+//! trips up analyses (and human readers) that assume loops have one entry
+//! point.
+
+use ruff_index::Idx;
+
+use super::visualize::{DirectedGraph, Predecessors, Successors};
+
+/// Computes the strongly-connected components of `graph`, using Tarjan's
+/// algorithm with an explicit stack (so we don't blow the stack on large
+/// graphs). Components are returned in reverse topological order, as
+/// Tarjan's algorithm naturally produces, alongside a `component_of` map
+/// from each node to the index of its component in that `Vec`.
+pub fn strongly_connected_components<'a, T>(graph: &T) -> (Vec<Vec<T::Node>>, Vec<usize>)
+where
+    T: DirectedGraph<'a> + Successors<'a> + ?Sized,
+{
+    let num_nodes = graph.num_nodes();
+    let mut index_of: Vec<Option<usize>> = vec![None; num_nodes];
+    let mut low_link: Vec<usize> = vec![0; num_nodes];
+    let mut on_stack: Vec<bool> = vec![false; num_nodes];
+    let mut stack: Vec<T::Node> = Vec::new();
+    let mut next_index = 0;
+    let mut components = Vec::new();
+
+    for start in 0..num_nodes {
+        let start = T::Node::new(start);
+        if index_of[start.index()].is_some() {
+            continue;
+        }
+        // (node, successors not yet visited)
+        let mut work: Vec<(T::Node, std::vec::IntoIter<T::Node>)> =
+            vec![(start, graph.successors(start).into_iter())];
+        index_of[start.index()] = Some(next_index);
+        low_link[start.index()] = next_index;
+        next_index += 1;
+        stack.push(start);
+        on_stack[start.index()] = true;
+
+        while let Some((node, successors)) = work.last_mut() {
+            let node = *node;
+            if let Some(successor) = successors.next() {
+                match index_of[successor.index()] {
+                    None => {
+                        index_of[successor.index()] = Some(next_index);
+                        low_link[successor.index()] = next_index;
+                        next_index += 1;
+                        stack.push(successor);
+                        on_stack[successor.index()] = true;
+                        work.push((successor, graph.successors(successor).into_iter()));
+                    }
+                    Some(successor_index) if on_stack[successor.index()] => {
+                        low_link[node.index()] = low_link[node.index()].min(successor_index);
+                    }
+                    Some(_) => {}
+                }
+            } else {
+                work.pop();
+                if let Some(&(parent, _)) = work.last() {
+                    low_link[parent.index()] = low_link[parent.index()].min(low_link[node.index()]);
+                }
+                if low_link[node.index()] == index_of[node.index()].unwrap() {
+                    let mut component = Vec::new();
+                    loop {
+                        let popped = stack.pop().expect("node was pushed before its SCC closed");
+                        on_stack[popped.index()] = false;
+                        component.push(popped);
+                        if popped == node {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+    }
+
+    let mut component_of = vec![0; num_nodes];
+    for (index, component) in components.iter().enumerate() {
+        for node in component {
+            component_of[node.index()] = index;
+        }
+    }
+
+    (components, component_of)
+}
+
+/// Whether `component` represents a loop in the control-flow graph: either
+/// it has more than one node, or its single node has an edge back to
+/// itself.
+pub fn is_loop<'a, T>(graph: &T, component: &[T::Node]) -> bool
+where
+    T: Successors<'a> + ?Sized,
+{
+    match component {
+        [] => false,
+        [node] => graph.successors(*node).contains(node),
+        _ => true,
+    }
+}
+
+/// Whether `component` is an *irreducible* loop: entered from more than
+/// one of its own nodes, so no single header dominates the rest of the
+/// loop body.
+pub fn is_irreducible<'a, T>(graph: &T, component: &[T::Node]) -> bool
+where
+    T: DirectedGraph<'a> + Predecessors<'a> + ?Sized,
+{
+    if component.len() <= 1 {
+        return false;
+    }
+
+    let mut entries: Vec<T::Node> = Vec::new();
+    for &node in component {
+        for predecessor in graph.predecessors(node) {
+            if !component.contains(&predecessor) && !entries.contains(&node) {
+                entries.push(node);
+            }
+        }
+    }
+    entries.len() > 1
+}
@@ -22,6 +22,15 @@ pub trait Successors<'a>: DirectedGraph<'a> {
     fn successors(&self, node: Self::Node) -> Vec<Self::Node>;
 }
 
+/// A graph that can report, for any node, the nodes with an edge into it.
+/// [`dominators`](super::dominators) only needs `successors` and builds the
+/// reverse adjacency list itself; [`scc::is_irreducible`](super::scc::is_irreducible)
+/// and [`dataflow::solve`](super::dataflow::solve) go through this trait,
+/// which is cheap for [`CFG`] since it stores predecessors.
+pub trait Predecessors<'a>: DirectedGraph<'a> {
+    fn predecessors(&self, node: Self::Node) -> Vec<Self::Node>;
+}
+
 #[derive(Debug, Default)]
 pub enum MermaidNodeShape {
     #[default]
@@ -161,6 +170,94 @@ pub trait MermaidGraph<'a>: DirectedGraph<'a> + Successors<'a> {
     }
 }
 
+/// A single Graphviz DOT node, e.g. `[shape=box, label="f()"]`.
+pub struct DotNode {
+    shape: &'static str,
+    label: String,
+}
+
+impl DotNode {
+    pub fn with_label(label: String) -> Self {
+        Self {
+            shape: "box",
+            label,
+        }
+    }
+
+    fn dot_write_quoted_str(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+        for c in value.chars() {
+            match c {
+                '"' => write!(f, "\\\"")?,
+                '\n' => write!(f, "\\n")?,
+                c => write!(f, "{c}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for DotNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[shape={}, label=\"", self.shape)?;
+        if self.label.is_empty() {
+            write!(f, "empty")?;
+        } else {
+            DotNode::dot_write_quoted_str(f, &self.label)?;
+        }
+        write!(f, "\"]")
+    }
+}
+
+/// A single Graphviz DOT edge, optionally labeled with its condition.
+#[derive(Default)]
+pub struct DotEdge {
+    label: String,
+}
+
+impl Display for DotEdge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.label.is_empty() {
+            Ok(())
+        } else {
+            write!(f, "[label=\"{}\"]", self.label.replace('"', "\\\""))
+        }
+    }
+}
+
+/// Renders a graph as Graphviz DOT, parallel to [`MermaidGraph`] for
+/// callers that would rather pipe the output through `dot`/`xdot` than
+/// paste it into a Mermaid viewer.
+pub trait DotGraph<'a>: DirectedGraph<'a> + Successors<'a> {
+    fn draw_node(&self, node: Self::Node) -> DotNode;
+    fn draw_edges(&self, node: Self::Node) -> impl Iterator<Item = (Self::Node, DotEdge)> {
+        self.successors(node)
+            .into_iter()
+            .map(|idx| (idx, DotEdge::default()))
+    }
+    fn draw_graph(&self) -> String {
+        let mut graph = Vec::new();
+
+        graph.push("digraph CFG {".to_string());
+
+        // Draw nodes
+        let num_nodes = self.num_nodes();
+        for idx in 0..num_nodes {
+            let node = Self::Node::new(idx);
+            graph.push(format!("\tnode{idx} {};", self.draw_node(node)));
+        }
+
+        // Draw edges
+        for idx in 0..num_nodes {
+            graph.extend(self.draw_edges(Self::Node::new(idx)).map(|(end_idx, edge)| {
+                format!("\tnode{idx} -> node{} {};", end_idx.index(), edge)
+            }))
+        }
+
+        graph.push("}".to_string());
+        graph.join("\n")
+    }
+}
+
 impl<'stmt, T: ControlFlowGraph<'stmt>> DirectedGraph<'stmt> for T
 where
     T::Block: Idx,
@@ -190,6 +287,15 @@ where
     }
 }
 
+impl<'stmt, T: ControlFlowGraph<'stmt>> Predecessors<'stmt> for T
+where
+    T::Block: Idx,
+{
+    fn predecessors(&self, node: Self::Node) -> Vec<Self::Node> {
+        ControlFlowGraph::predecessors(self, node).into_iter().collect()
+    }
+}
+
 pub(crate) struct CFGWithSource<'stmt> {
     cfg: CFG<'stmt>,
     source: &'stmt str,
@@ -221,6 +327,12 @@ impl<'stmt> Successors<'stmt> for CFGWithSource<'stmt> {
     }
 }
 
+impl<'stmt> Predecessors<'stmt> for CFGWithSource<'stmt> {
+    fn predecessors(&self, node: Self::Node) -> Vec<Self::Node> {
+        Predecessors::predecessors(&self.cfg, node)
+    }
+}
+
 impl<'stmt> MermaidGraph<'stmt> for CFGWithSource<'stmt> {
     fn draw_node(&self, node: Self::Node) -> MermaidNode {
         let statements: Vec<String> = self
@@ -304,6 +416,18 @@ impl<'stmt> MermaidGraph<'stmt> for CFGWithSource<'stmt> {
                             content: format!("except {}", exc_types),
                         }
                     }
+                    Condition::ExceptionSuppressed => MermaidEdge {
+                        kind: MermaidEdgeKind::DottedArrow,
+                        content: "suppressed".to_string(),
+                    },
+                    Condition::UncaughtException => MermaidEdge {
+                        kind: MermaidEdgeKind::DottedArrow,
+                        content: "raises".to_string(),
+                    },
+                    Condition::Deferred(stmt) => MermaidEdge {
+                        kind: MermaidEdgeKind::DottedArrow,
+                        content: self.source[stmt.range()].to_string(),
+                    },
                     Condition::Else => {
                         if target == self.cfg.terminal() {
                             MermaidEdge {
@@ -325,6 +449,72 @@ impl<'stmt> MermaidGraph<'stmt> for CFGWithSource<'stmt> {
     }
 }
 
+impl<'stmt> DotGraph<'stmt> for CFGWithSource<'stmt> {
+    fn draw_node(&self, node: Self::Node) -> DotNode {
+        let statements: Vec<String> = self
+            .cfg
+            .stmts(node)
+            .into_iter()
+            .map(|stmt| self.source[stmt.range()].to_string())
+            .collect();
+
+        // Special case for terminal block
+        if node == self.cfg.terminal() && statements.is_empty() {
+            return DotNode::with_label("EXIT".to_string());
+        }
+
+        let content = if statements.is_empty() {
+            "EMPTY".to_string()
+        } else {
+            statements.join("\n")
+        };
+
+        DotNode::with_label(content)
+    }
+
+    fn draw_edges(&self, node: Self::Node) -> impl Iterator<Item = (Self::Node, DotEdge)> {
+        let edge_data = self.cfg.out(node);
+        edge_data
+            .targets()
+            .zip(edge_data.conditions())
+            .map(|(target, condition)| {
+                let label = match condition {
+                    Condition::Test(expr) => self.source[expr.range()].to_string(),
+                    Condition::Always => String::new(),
+                    Condition::Match { subject, case } => {
+                        let pattern = &self.source[case.pattern.range()];
+                        let subject = &self.source[subject.range()];
+                        format!("{} matches {}", subject, pattern)
+                    }
+                    Condition::Iterator {
+                        target,
+                        iter,
+                        is_async,
+                    } => {
+                        let target = &self.source[target.range()];
+                        let iter = &self.source[iter.range()];
+                        let prefix = if is_async { "async " } else { "" };
+                        format!("{}for {} in {}", prefix, target, iter)
+                    }
+                    Condition::ExceptHandler(handler) => {
+                        let exc_types = match &handler.as_except_handler().unwrap().type_ {
+                            Some(t) => self.source[t.range()].to_string(),
+                            None => "any exception".to_string(),
+                        };
+                        format!("except {}", exc_types)
+                    }
+                    Condition::ExceptionSuppressed => "suppressed".to_string(),
+                    Condition::UncaughtException => "raises".to_string(),
+                    Condition::Deferred(stmt) => self.source[stmt.range()].to_string(),
+                    Condition::Else => "Else".to_string(),
+                };
+                (target, DotEdge { label })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
 impl<'stmt> CFGWithSource<'stmt> {
     // Add debug method to print all edges
     pub fn debug_edges(&self) {
@@ -340,4 +530,38 @@ impl<'stmt> CFGWithSource<'stmt> {
             );
         }
     }
+
+    /// Returns the blocks that cannot be reached from the entry block, e.g.
+    /// code after an unconditional `return`/`raise` that nothing jumps
+    /// past.
+    pub fn unreachable_blocks(&self) -> Vec<BlockId> {
+        let reachable = self.cfg.reachable_blocks();
+        (0..self.cfg.num_blocks())
+            .map(BlockId::new)
+            .filter(|block| !reachable.contains(block))
+            .collect()
+    }
+
+    /// Renders a human-readable report of unreachable code: one entry per
+    /// unreachable block that contains at least one statement.
+    pub fn unreachable_report(&self) -> String {
+        let mut report = String::new();
+        for block in self.unreachable_blocks() {
+            let statements: Vec<&str> = self
+                .cfg
+                .stmts(block)
+                .into_iter()
+                .map(|stmt| &self.source[stmt.range()])
+                .collect();
+            if statements.is_empty() {
+                continue;
+            }
+            report.push_str(&format!("unreachable block {}:\n", block.index()));
+            for stmt in statements {
+                report.push_str(stmt);
+                report.push('\n');
+            }
+        }
+        report
+    }
 }